@@ -0,0 +1,190 @@
+//! Collects the solutions in `src/bin` so the main binary can run them in-process, and
+//! generates the example tests of every solution from the answers sidecars in `data`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+include!("src/template/layout.rs");
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<String> = vec![];
+
+    // dhat installs a global allocator per solution, which cannot be linked into a single binary.
+    if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_none() {
+        if let Ok(entries) = fs::read_dir(&bin_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let is_solution = path.extension().is_some_and(|ext| ext == "rs")
+                    && path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .is_some_and(is_solution_name);
+
+                if is_solution {
+                    println!("cargo:rerun-if-changed={}", path.display());
+                    days.push(path.file_stem().unwrap().to_string_lossy().into_owned());
+                }
+            }
+        }
+    }
+
+    days.sort();
+
+    write_example_tests(Path::new(&manifest_dir), &bin_dir);
+
+    let mut registry = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        registry.push_str(&format!(
+            "#[path = {:?}]\n#[cfg(not(test))]\nmod day_{};\n",
+            path.display().to_string(),
+            day.replace('-', "_")
+        ));
+    }
+
+    registry.push_str("\npub static SOLUTIONS: &[&dyn advent_of_code::template::Solution] = &[\n");
+    for day in &days {
+        registry.push_str(&format!(
+            "    #[cfg(not(test))]\n    &day_{}::DaySolution,\n",
+            day.replace('-', "_")
+        ));
+    }
+    registry.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
+
+/// Solutions are named `NN` for the [`FLAT_YEAR`] and `YYYY-NN` for other years.
+fn is_solution_name(stem: &str) -> bool {
+    let day = match stem.split_once('-') {
+        Some((year, day)) if year.len() == 4 && year.parse::<u16>().is_ok() => day,
+        Some(_) => return false,
+        None => stem,
+    };
+
+    day.len() == 2 && day.parse::<u8>().is_ok()
+}
+
+/// Writes `OUT_DIR/examples/<bin>.rs` for every solution, which the `example_tests!` macro
+/// includes. Every example `NN.txt` or `NN-<suffix>.txt` that has a sidecar `NN.answers` or
+/// `NN-<suffix>.answers` gets one test per part listed in the sidecar, e.g. `2: 126`.
+fn write_example_tests(manifest_dir: &Path, bin_dir: &Path) {
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("examples");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    println!("cargo:rerun-if-changed=src/template/layout.rs");
+    let flat_year = FLAT_YEAR.to_string();
+
+    let Ok(entries) = fs::read_dir(bin_dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        if path.extension().is_none_or(|ext| ext != "rs") || !is_solution_name(stem) {
+            continue;
+        }
+
+        let (examples_dir, day) = match stem.split_once('-') {
+            Some((year, day)) if year != flat_year => {
+                (manifest_dir.join("data").join(year).join("examples"), day)
+            }
+            Some((_, day)) => (manifest_dir.join("data").join("examples"), day),
+            None => (manifest_dir.join("data").join("examples"), stem),
+        };
+
+        if examples_dir.is_dir() {
+            println!("cargo:rerun-if-changed={}", examples_dir.display());
+        }
+
+        let tests: String = examples_of(&examples_dir, day)
+            .iter()
+            .map(example_test)
+            .collect();
+
+        fs::write(out_dir.join(format!("{stem}.rs")), tests).unwrap();
+    }
+}
+
+/// An example with an answers sidecar.
+struct Example {
+    /// File stem of the example, e.g. `07-2`.
+    name: String,
+    input: PathBuf,
+    /// The expected answer of each part listed in the sidecar.
+    answers: Vec<(u8, String)>,
+}
+
+/// Lists the examples of a day that have a sidecar, sorted by name.
+fn examples_of(dir: &Path, day: &str) -> Vec<Example> {
+    let mut examples = vec![];
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return examples;
+    };
+
+    for entry in entries.flatten() {
+        let sidecar = entry.path();
+        let Some(name) = sidecar.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let is_example = name == day || name.starts_with(&format!("{day}-"));
+        if sidecar.extension().is_none_or(|ext| ext != "answers") || !is_example {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&sidecar).unwrap();
+        let answers = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_once(':')
+                    .and_then(|(part, answer)| Some((part.trim().parse().ok()?, answer.trim())))
+                    .filter(|(part, _)| matches!(part, 1 | 2))
+                    .map(|(part, answer)| (part, answer.to_string()))
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "invalid line `{line}` in {}, expecting `<part>: <answer>`",
+                            sidecar.display()
+                        )
+                    })
+            })
+            .collect();
+
+        examples.push(Example {
+            name: name.to_string(),
+            input: sidecar.with_extension("txt"),
+            answers,
+        });
+    }
+
+    examples.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+fn example_test(example: &Example) -> String {
+    example
+        .answers
+        .iter()
+        .map(|(part, answer)| {
+            let func = if *part == 1 { "part_one" } else { "part_two" };
+            format!(
+                "#[test]\nfn example_{}_part_{part}() {{\n    let input = ::std::fs::read_to_string({:?}).unwrap();\n    let result = ::advent_of_code::template::runner::PartOutput::into_answer({func}(&input))\n        .map(|answer| answer.map(|answer| answer.to_string()));\n    assert_eq!(result, Ok(Some({answer:?}.to_string())));\n}}\n",
+                example.name.replace('-', "_"),
+                example.input.display().to_string(),
+            )
+        })
+        .collect()
+}
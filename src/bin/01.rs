@@ -1,32 +1,30 @@
+use std::num::ParseIntError;
+
 use itertools::iproduct;
 advent_of_code::solution!(1);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let lines = input.lines();
+fn parse(input: &str) -> Result<Vec<u64>, ParseIntError> {
+    input.lines().map(str::parse).collect()
+}
 
-    let numbers: Vec<_> = lines
-        .map(|line| line.parse::<u64>().ok().unwrap())
-        .collect();
+pub fn part_one(input: &str) -> Result<Option<u64>, ParseIntError> {
+    let numbers = parse(input)?;
 
-    let (i, j) = iproduct!(numbers.clone(), numbers)
+    let result = iproduct!(numbers.clone(), numbers)
         .find(|(i, j)| i + j == 2020)
-        .unwrap();
+        .map(|(i, j)| i * j);
 
-    Some(i * j)
+    Ok(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let lines = input.lines();
-
-    let numbers: Vec<_> = lines
-        .map(|line| line.parse::<u64>().ok().unwrap())
-        .collect();
+pub fn part_two(input: &str) -> Result<Option<u64>, ParseIntError> {
+    let numbers = parse(input)?;
 
-    let (i, j, k) = iproduct!(numbers.clone(), numbers.clone(), numbers)
+    let result = iproduct!(numbers.clone(), numbers.clone(), numbers)
         .find(|(i, j, k)| i + j + k == 2020)
-        .unwrap();
+        .map(|(i, j, k)| i * j * k);
 
-    Some(i * j * k)
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -36,12 +34,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(514579));
+        assert_eq!(result, Ok(Some(514579)));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(241861950));
+        assert_eq!(result, Ok(Some(241861950)));
     }
 }
@@ -7,7 +7,7 @@ pub struct Row<'a> {
     password: &'a str,
 }
 
-pub fn parse_row(input: &str) -> Row {
+pub fn parse_row(input: &str) -> Row<'_> {
     let mut parts = input.split(' ');
 
     let min_max = parts.next().unwrap();
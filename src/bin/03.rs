@@ -1,24 +1,31 @@
+use std::iter;
+
+use advent_of_code::grid::{input_to_grid, CustomGrid, EdgePolicy};
 advent_of_code::solution!(3);
 
-fn with_slope(input: &str, slope_right: usize, slope_down: usize) -> usize {
-    input
-        .lines()
-        .step_by(slope_down)
-        .enumerate()
-        .filter(|(i, line)| line.chars().nth((slope_right * *i) % line.len()).unwrap() == '#')
-        .count()
+/// Counts the trees hit going down the slope, the forest repeats to the right.
+fn with_slope(grid: &CustomGrid<char>, slope_right: usize, slope_down: usize) -> usize {
+    let step = (slope_down as isize, slope_right as isize);
+
+    iter::successors(Some((0, 0)), |&(row, col)| {
+        grid.offset(row, col, step, EdgePolicy::WrapHorizontal)
+    })
+    .filter(|&(row, col)| grid.get(row, col) == Some(&'#'))
+    .count()
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    Some(with_slope(input, 3, 1))
+    let grid = input_to_grid(input).ok()?;
+    Some(with_slope(&grid, 3, 1))
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let result = with_slope(input, 1, 1)
-        * with_slope(input, 3, 1)
-        * with_slope(input, 5, 1)
-        * with_slope(input, 7, 1)
-        * with_slope(input, 1, 2);
+    let grid = input_to_grid(input).ok()?;
+    let result = with_slope(&grid, 1, 1)
+        * with_slope(&grid, 3, 1)
+        * with_slope(&grid, 5, 1)
+        * with_slope(&grid, 7, 1)
+        * with_slope(&grid, 1, 2);
 
     Some(result)
 }
@@ -45,6 +45,7 @@ struct Passport<'a> {
     hair_color: &'a str,
     eye_color: &'a str,
     passport_id: &'a str,
+    #[allow(dead_code)]
     country_id: Option<u32>,
 }
 #[derive(Debug, PartialEq, Eq)]
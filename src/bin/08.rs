@@ -36,7 +36,7 @@ enum ProgramExecutionResult {
     Ended(u32),
 }
 
-fn execute_program(program: &Vec<Instruction>) -> ProgramExecutionResult {
+fn execute_program(program: &[Instruction]) -> ProgramExecutionResult {
     let mut pc = 0usize;
     let mut acc = 0;
     let mut visited = FxHashSet::default();
@@ -60,7 +60,7 @@ pub fn part_one(input: &str) -> Option<u32> {
             },
         );
 
-    Some(east.abs() as u32 + north.abs() as u32)
+    Some(east.unsigned_abs() + north.unsigned_abs())
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
@@ -87,7 +87,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             },
         );
 
-    Some(east.abs() as u32 + north.abs() as u32)
+    Some(east.unsigned_abs() + north.unsigned_abs())
 }
 
 #[cfg(test)]
@@ -86,7 +86,6 @@ pub fn part_one(input: &str) -> Option<u64> {
                 Instruction::SetMemory(address, value) => {
                     state.memory.insert(address, state.mask.apply(value));
                 }
-                _ => panic!("unreachable"),
             }
             state
         });
@@ -94,7 +93,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(state.memory.values().sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
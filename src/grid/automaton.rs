@@ -0,0 +1,234 @@
+use std::{collections::HashMap, hash::Hash, mem};
+
+use super::{CustomGrid, EdgePolicy};
+
+/// A cellular automaton that updates every cell of a grid at once, based on the cell and its
+/// neighborhood. Keeps two grids around and swaps them after each step instead of cloning.
+pub struct Automaton<T> {
+    current: CustomGrid<T>,
+    next: CustomGrid<T>,
+    offsets: Vec<(isize, isize)>,
+    edges: EdgePolicy,
+    generation: usize,
+}
+
+/// The view of a cell that a rule receives.
+pub struct Cell<'a, T> {
+    grid: &'a CustomGrid<T>,
+    row: usize,
+    col: usize,
+    offsets: &'a [(isize, isize)],
+    edges: EdgePolicy,
+}
+
+/// Why [`Automaton::run`] stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// A step changed nothing, the grid is stable from `generation` on.
+    FixedPoint { generation: usize },
+    /// The grid of generation `start` came back every `period` steps.
+    Cycle { start: usize, period: usize },
+    /// The step limit was reached at `generation` without the grid settling.
+    StepLimit { generation: usize },
+}
+
+impl<'a, T> Cell<'a, T> {
+    pub fn value(&self) -> &'a T {
+        &self.grid[(self.row, self.col)]
+    }
+
+    pub fn position(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// The grid of the current generation, for rules that look beyond the neighborhood.
+    pub fn grid(&self) -> &'a CustomGrid<T> {
+        self.grid
+    }
+
+    /// The offsets of the neighborhood the automaton was created with.
+    pub fn offsets(&self) -> &'a [(isize, isize)] {
+        self.offsets
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.offsets.iter().filter_map(|&offset| {
+            self.grid
+                .get_offset(self.row, self.col, offset, self.edges)
+                .map(|(_, val)| val)
+        })
+    }
+
+    pub fn count_neighbors(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.neighbors().filter(|val| predicate(val)).count()
+    }
+}
+
+impl<T: Clone + PartialEq> Automaton<T> {
+    /// Creates an automaton whose rules see the neighbors at `offsets`, see [`Neighborhood`].
+    ///
+    /// [`Neighborhood`]: super::Neighborhood
+    pub fn new(grid: CustomGrid<T>, offsets: &[(isize, isize)], edges: EdgePolicy) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            offsets: offsets.to_vec(),
+            edges,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &CustomGrid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> CustomGrid<T> {
+        self.current
+    }
+
+    /// The number of steps applied so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Applies `rule` to every cell at once. Returns whether any cell changed.
+    pub fn step(&mut self, mut rule: impl FnMut(&Cell<T>) -> T) -> bool {
+        let mut changed = false;
+
+        for ((row, col), next) in self.next.indexed_iter_mut() {
+            let cell = Cell {
+                grid: &self.current,
+                row,
+                col,
+                offsets: &self.offsets,
+                edges: self.edges,
+            };
+
+            let val = rule(&cell);
+            changed |= val != *cell.value();
+            *next = val;
+        }
+
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Steps until the grid stops changing, or `limit` steps have been applied.
+    pub fn run(&mut self, limit: Option<usize>, mut rule: impl FnMut(&Cell<T>) -> T) -> Outcome {
+        loop {
+            if limit.is_some_and(|limit| self.generation >= limit) {
+                return Outcome::StepLimit {
+                    generation: self.generation,
+                };
+            }
+
+            if !self.step(&mut rule) {
+                return Outcome::FixedPoint {
+                    generation: self.generation - 1,
+                };
+            }
+        }
+    }
+
+    /// Like [`Automaton::run`], but also stops when the grid repeats an earlier generation.
+    /// Keeps a copy of every generation, so only use it where cycles are expected.
+    pub fn run_until_cycle(
+        &mut self,
+        limit: Option<usize>,
+        mut rule: impl FnMut(&Cell<T>) -> T,
+    ) -> Outcome
+    where
+        T: Hash + Eq,
+    {
+        let mut seen: HashMap<Vec<T>, usize> = HashMap::new();
+
+        loop {
+            if let Some(&start) = seen.get(self.current.flatten()) {
+                return Outcome::Cycle {
+                    start,
+                    period: self.generation - start,
+                };
+            }
+            seen.insert(self.current.flatten().clone(), self.generation);
+
+            if limit.is_some_and(|limit| self.generation >= limit) {
+                return Outcome::StepLimit {
+                    generation: self.generation,
+                };
+            }
+
+            if !self.step(&mut rule) {
+                return Outcome::FixedPoint {
+                    generation: self.generation - 1,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{input_to_grid, Neighborhood};
+
+    /// Conway's game of life.
+    fn life(cell: &Cell<char>) -> char {
+        match (cell.value(), cell.count_neighbors(|&c| c == '#')) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    fn automaton(input: &str, edges: EdgePolicy) -> Automaton<char> {
+        let grid = input_to_grid(input).unwrap();
+        Automaton::new(grid, Neighborhood::SURROUNDING, edges)
+    }
+
+    #[test]
+    fn test_step() {
+        let mut blinker = automaton(".....\n..#..\n..#..\n..#..\n.....", EdgePolicy::Clip);
+
+        assert!(blinker.step(life));
+        assert_eq!(blinker.generation(), 1);
+        assert_eq!(
+            blinker.grid().iter().collect::<String>(),
+            "...........###..........."
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut block = automaton("....\n.##.\n.##.\n....", EdgePolicy::Clip);
+        assert_eq!(block.run(None, life), Outcome::FixedPoint { generation: 0 });
+
+        let mut blinker = automaton(".....\n..#..\n..#..\n..#..\n.....", EdgePolicy::Clip);
+        assert_eq!(
+            blinker.run(Some(5), life),
+            Outcome::StepLimit { generation: 5 }
+        );
+    }
+
+    #[test]
+    fn test_run_until_cycle() {
+        let mut blinker = automaton(".....\n..#..\n..#..\n..#..\n.....", EdgePolicy::Clip);
+        assert_eq!(
+            blinker.run_until_cycle(None, life),
+            Outcome::Cycle {
+                start: 0,
+                period: 2
+            }
+        );
+
+        // A glider on a torus comes back to where it started after moving across the grid.
+        let mut glider = automaton(".#...\n..#..\n###..\n.....\n.....", EdgePolicy::Toroidal);
+        assert_eq!(
+            glider.run_until_cycle(None, life),
+            Outcome::Cycle {
+                start: 0,
+                period: 20
+            }
+        );
+    }
+}
@@ -1,7 +1,31 @@
+pub use automaton::*;
 use grid::*;
+pub use neighborhood::*;
+pub use parse::*;
+pub use sparse::*;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Deref, DerefMut};
-use std::str::FromStr;
+
+mod automaton;
+mod neighborhood;
+mod parse;
+mod ray;
+mod sparse;
+
+/// The `(row, col)` offsets of [`CustomGrid::iter_neighbors`]: up, down, right and left.
+const NEIGHBORS: &[(isize, isize)] = &[(-1, 0), (1, 0), (0, 1), (0, -1)];
+
+/// The `(row, col)` offsets of [`CustomGrid::iter_diagonal_neighbors`], column by column.
+const DIAGONAL_NEIGHBORS: &[(isize, isize)] = &[
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
 
 pub struct CustomGrid<T>(Grid<T>);
 
@@ -38,55 +62,24 @@ impl<T: Display> Debug for CustomGrid<T> {
 }
 
 impl<T> CustomGrid<T> {
+    /// Iterates the 4 orthogonal neighbors of a cell inside the grid, in the order up, down,
+    /// right, left. Unlike [`Neighborhood::ORTHOGONAL`], which is in row-major order.
     pub fn iter_neighbors(
         &self,
         row: usize,
         col: usize,
     ) -> impl Iterator<Item = ((usize, usize), &T)> {
-        [(0, -1), (0, 1), (1, 0), (-1, 0)]
-            .iter()
-            .map(move |(col_offset, row_offset)| {
-                ((col as isize + col_offset), (row as isize + row_offset))
-            })
-            .filter_map(|(col, row)| {
-                if col.is_negative() || row.is_negative() {
-                    None
-                } else {
-                    self.0
-                        .get(row as usize, col as usize)
-                        .map(|val| ((row as usize, col as usize), val))
-                }
-            })
+        self.iter_neighborhood(row, col, NEIGHBORS, EdgePolicy::Clip)
     }
 
+    /// Iterates the 8 orthogonal and diagonal neighbors of a cell inside the grid, column by
+    /// column. Unlike [`Neighborhood::SURROUNDING`], which is in row-major order.
     pub fn iter_diagonal_neighbors(
         &self,
         row: usize,
         col: usize,
     ) -> impl Iterator<Item = ((usize, usize), &T)> {
-        [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ]
-        .iter()
-        .map(move |(col_offset, row_offset)| {
-            ((col as isize + col_offset), (row as isize + row_offset))
-        })
-        .filter_map(|(col, row)| {
-            if col.is_negative() || row.is_negative() {
-                None
-            } else {
-                self.0
-                    .get(row as usize, col as usize)
-                    .map(|val| ((row as usize, col as usize), val))
-            }
-        })
+        self.iter_neighborhood(row, col, DIAGONAL_NEIGHBORS, EdgePolicy::Clip)
     }
 
     pub fn right(&self, row: usize, col: usize) -> Option<&T> {
@@ -181,15 +174,21 @@ impl<T: Display> CustomGrid<T> {
     }
 }
 
-pub fn input_to_grid<T: FromStr>(input: &str) -> Result<CustomGrid<T>, <T as FromStr>::Err> {
-    let lines: Vec<&str> = input.lines().map(|line| line.trim()).collect();
-    let cols = lines[0].len();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let grid_data: Result<Vec<T>, <T as FromStr>::Err> = lines
-        .into_iter()
-        .flat_map(|line| line.chars())
-        .map(|c| c.to_string().parse::<T>())
-        .collect();
+    #[test]
+    fn test_neighbor_order() {
+        let grid = input_to_grid::<char>("abc\ndef\nghi").unwrap();
 
-    Ok(CustomGrid(Grid::from_vec(grid_data?, cols)))
+        let neighbors: String = grid.iter_neighbors(1, 1).map(|(_, c)| *c).collect();
+        assert_eq!(neighbors, "bhfd");
+
+        let diagonal: String = grid
+            .iter_diagonal_neighbors(1, 1)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(diagonal, "adgbhcfi");
+    }
 }
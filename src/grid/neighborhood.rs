@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+
+use super::CustomGrid;
+
+/// The cells around a cell, described by their `(row, col)` offsets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Cells within the given manhattan distance, i.e. the 4 orthogonal neighbors for radius 1.
+    VonNeumann(usize),
+    /// Cells within the given chebyshev distance, i.e. the 8 surrounding neighbors for radius 1.
+    Moore(usize),
+    /// Arbitrary `(row, col)` offsets, e.g. the moves of a knight.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    /// The 4 orthogonal neighbors, i.e. `VonNeumann(1)`.
+    pub const ORTHOGONAL: &'static [(isize, isize)] = &[(-1, 0), (0, -1), (0, 1), (1, 0)];
+    /// The 8 orthogonal and diagonal neighbors, i.e. `Moore(1)`.
+    pub const SURROUNDING: &'static [(isize, isize)] = &[
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+
+    /// The `(row, col)` offsets of the neighborhood in row-major order, without the cell itself.
+    /// Only neighborhoods with a radius above 1 are computed, the others are borrowed.
+    pub fn offsets(&self) -> Cow<'_, [(isize, isize)]> {
+        let (radius, is_within): (usize, fn(isize, isize, isize) -> bool) = match self {
+            Neighborhood::Custom(offsets) => return Cow::Borrowed(offsets),
+            Neighborhood::VonNeumann(1) => return Cow::Borrowed(Self::ORTHOGONAL),
+            Neighborhood::Moore(1) => return Cow::Borrowed(Self::SURROUNDING),
+            Neighborhood::VonNeumann(radius) => {
+                (*radius, |row, col, radius| row.abs() + col.abs() <= radius)
+            }
+            Neighborhood::Moore(radius) => (*radius, |_, _, _| true),
+        };
+
+        let radius = radius as isize;
+
+        (-radius..=radius)
+            .flat_map(|row| (-radius..=radius).map(move |col| (row, col)))
+            .filter(|&(row, col)| (row, col) != (0, 0) && is_within(row, col, radius))
+            .collect()
+    }
+}
+
+/// What happens to positions beyond the edges of a grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EdgePolicy {
+    /// Positions outside the grid do not exist.
+    #[default]
+    Clip,
+    /// The grid repeats to the left and right, rows are clipped.
+    WrapHorizontal,
+    /// The grid repeats above and below, columns are clipped.
+    WrapVertical,
+    /// The grid repeats in every direction.
+    Toroidal,
+}
+
+impl EdgePolicy {
+    fn wraps_rows(self) -> bool {
+        matches!(self, EdgePolicy::WrapVertical | EdgePolicy::Toroidal)
+    }
+
+    fn wraps_cols(self) -> bool {
+        matches!(self, EdgePolicy::WrapHorizontal | EdgePolicy::Toroidal)
+    }
+}
+
+/// Resolves a coordinate that may lie outside of `0..len`.
+fn resolve(index: usize, offset: isize, len: usize, wrap: bool) -> Option<usize> {
+    let len = isize::try_from(len).ok()?;
+    let index = isize::try_from(index).ok()? + offset;
+
+    if wrap && len > 0 {
+        Some(index.rem_euclid(len) as usize)
+    } else if (0..len).contains(&index) {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+impl<T> CustomGrid<T> {
+    /// Moves from a cell by a `(row, col)` offset. Returns [`None`] if the position lies outside
+    /// the grid after applying the edge policy.
+    pub fn offset(
+        &self,
+        row: usize,
+        col: usize,
+        (row_offset, col_offset): (isize, isize),
+        edges: EdgePolicy,
+    ) -> Option<(usize, usize)> {
+        Some((
+            resolve(row, row_offset, self.0.rows(), edges.wraps_rows())?,
+            resolve(col, col_offset, self.0.cols(), edges.wraps_cols())?,
+        ))
+    }
+
+    /// Returns the cell at a `(row, col)` offset from a cell, see [`CustomGrid::offset`].
+    pub fn get_offset(
+        &self,
+        row: usize,
+        col: usize,
+        offset: (isize, isize),
+        edges: EdgePolicy,
+    ) -> Option<((usize, usize), &T)> {
+        let (row, col) = self.offset(row, col, offset, edges)?;
+        self.0.get(row, col).map(|val| ((row, col), val))
+    }
+
+    /// Iterates the neighbors of a cell at the given offsets, e.g. [`Neighborhood::ORTHOGONAL`] or
+    /// `&Neighborhood::Moore(2).offsets()`. On grids smaller than the neighborhood, wrapping edges
+    /// can yield the same cell more than once, or the cell itself.
+    pub fn iter_neighborhood<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        offsets: &'a [(isize, isize)],
+        edges: EdgePolicy,
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.get_offset(row, col, offset, edges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::input_to_grid;
+
+    fn positions<T>(neighbors: impl Iterator<Item = ((usize, usize), T)>) -> Vec<(usize, usize)> {
+        neighbors.map(|(position, _)| position).collect()
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert!(matches!(
+            Neighborhood::VonNeumann(1).offsets(),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(Neighborhood::Moore(1).offsets(), Cow::Borrowed(_)));
+        assert_eq!(Neighborhood::VonNeumann(2).offsets().len(), 12);
+        assert_eq!(Neighborhood::Moore(2).offsets().len(), 24);
+        assert_eq!(*Neighborhood::Custom(vec![(2, 1)]).offsets(), [(2, 1)]);
+
+        // The borrowed neighborhoods match the computed ones of radius 2, cut down to radius 1.
+        let von_neumann = Neighborhood::VonNeumann(2).offsets();
+        let moore = Neighborhood::Moore(2).offsets();
+        assert!(von_neumann
+            .iter()
+            .filter(|(row, col)| row.abs() + col.abs() <= 1)
+            .eq(Neighborhood::ORTHOGONAL));
+        assert!(moore
+            .iter()
+            .filter(|(row, col)| row.abs() <= 1 && col.abs() <= 1)
+            .eq(Neighborhood::SURROUNDING));
+    }
+
+    #[test]
+    fn test_edge_policies() {
+        let grid = input_to_grid::<char>("abc\ndef\nghi").unwrap();
+
+        let clipped = grid.iter_neighborhood(0, 0, Neighborhood::ORTHOGONAL, EdgePolicy::Clip);
+        assert_eq!(positions(clipped), [(0, 1), (1, 0)]);
+
+        let horizontal =
+            grid.iter_neighborhood(0, 0, Neighborhood::ORTHOGONAL, EdgePolicy::WrapHorizontal);
+        assert_eq!(positions(horizontal), [(0, 2), (0, 1), (1, 0)]);
+
+        let vertical =
+            grid.iter_neighborhood(0, 0, Neighborhood::ORTHOGONAL, EdgePolicy::WrapVertical);
+        assert_eq!(positions(vertical), [(2, 0), (0, 1), (1, 0)]);
+
+        let toroidal =
+            grid.iter_neighborhood(0, 0, Neighborhood::SURROUNDING, EdgePolicy::Toroidal);
+        assert_eq!(toroidal.map(|(_, c)| *c).collect::<String>(), "ighcbfde");
+    }
+
+    #[test]
+    fn test_get_offset() {
+        let grid = input_to_grid::<char>("abc\ndef").unwrap();
+
+        assert_eq!(
+            grid.get_offset(1, 2, (0, 2), EdgePolicy::WrapHorizontal),
+            Some(((1, 1), &'e'))
+        );
+        assert_eq!(
+            grid.get_offset(1, 2, (1, 0), EdgePolicy::WrapHorizontal),
+            None
+        );
+        assert_eq!(
+            grid.get_offset(1, 2, (1, 0), EdgePolicy::Toroidal),
+            Some(((0, 2), &'c'))
+        );
+    }
+}
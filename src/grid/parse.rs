@@ -0,0 +1,226 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use grid::Grid;
+
+use super::CustomGrid;
+
+/// Why an input could not be parsed into a grid. Lines and columns start at 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError<E> {
+    /// The input has no rows.
+    Empty,
+    /// A row has a different number of cells than the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A cell was rejected by the parser. For separated grids, `column` counts cells.
+    Cell {
+        line: usize,
+        column: usize,
+        source: E,
+    },
+}
+
+impl<E: Display> Display for ParseError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "the grid is empty"),
+            ParseError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} cells, expected {expected} like the first line"
+            ),
+            ParseError::Cell {
+                line,
+                column,
+                source,
+            } => write!(f, "invalid cell at line {line}, column {column}: {source}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Cell { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The `(row, col)` positions of every marker character found while parsing, in row-major order.
+pub type Markers = HashMap<char, Vec<(usize, usize)>>;
+
+/// Parses every char of the input with its [`FromStr`] implementation.
+pub fn input_to_grid<T: FromStr>(input: &str) -> Result<CustomGrid<T>, ParseError<T::Err>> {
+    parse_grid(input, |c| c.encode_utf8(&mut [0; 4]).parse())
+}
+
+/// Parses a grid with one char per cell, e.g. `#` and `.` into booleans.
+pub fn parse_grid<T, E>(
+    input: &str,
+    parse: impl FnMut(char) -> Result<T, E>,
+) -> Result<CustomGrid<T>, ParseError<E>> {
+    parse_grid_with_markers(input, &[], parse).map(|(grid, _)| grid)
+}
+
+/// Like [`parse_grid`], but also returns where the `markers` are, e.g. the start `S` and end `E`
+/// of a maze. Markers are passed to `parse` like every other char, so they get a cell value too.
+pub fn parse_grid_with_markers<T, E>(
+    input: &str,
+    markers: &[char],
+    mut parse: impl FnMut(char) -> Result<T, E>,
+) -> Result<(CustomGrid<T>, Markers), ParseError<E>> {
+    let mut found = Markers::new();
+
+    let grid = build(input, |row, line| {
+        line.chars()
+            .enumerate()
+            .map(|(col, c)| {
+                if markers.contains(&c) {
+                    found.entry(c).or_default().push((row, col));
+                }
+                parse(c).map_err(|source| (col, source))
+            })
+            .collect()
+    })?;
+
+    Ok((grid, found))
+}
+
+/// Parses a grid with cells of several chars, like numbers, split at `separator`. Empty cells are
+/// skipped, so separators may be repeated to align the columns, e.g. ` 8  2 23`.
+pub fn parse_separated_grid<T, E>(
+    input: &str,
+    separator: char,
+    mut parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<CustomGrid<T>, ParseError<E>> {
+    build(input, |_, line| {
+        line.split(separator)
+            .filter(|cell| !cell.is_empty())
+            .enumerate()
+            .map(|(col, cell)| parse(cell).map_err(|source| (col, source)))
+            .collect()
+    })
+}
+
+/// Parses the rows of a grid with `parse_row`, which fails with the 0-based column of a bad cell.
+fn build<T, E>(
+    input: &str,
+    mut parse_row: impl FnMut(usize, &str) -> Result<Vec<T>, (usize, E)>,
+) -> Result<CustomGrid<T>, ParseError<E>> {
+    let mut lines: Vec<&str> = input.lines().map(|line| line.trim_end()).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let mut cells = vec![];
+    let mut cols = 0;
+
+    for (row, line) in lines.into_iter().enumerate() {
+        let parsed = parse_row(row, line).map_err(|(col, source)| ParseError::Cell {
+            line: row + 1,
+            column: col + 1,
+            source,
+        })?;
+
+        if row == 0 {
+            cols = parsed.len();
+        } else if parsed.len() != cols {
+            return Err(ParseError::Ragged {
+                line: row + 1,
+                expected: cols,
+                found: parsed.len(),
+            });
+        }
+
+        cells.extend(parsed);
+    }
+
+    if cells.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    Ok(CustomGrid(Grid::from_vec(cells, cols)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_to_grid() {
+        let grid = input_to_grid::<u8>("123\n456\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.get(1, 2), Some(&6));
+
+        assert_eq!(input_to_grid::<char>("").unwrap_err(), ParseError::Empty);
+        assert_eq!(
+            input_to_grid::<char>("\n\n").unwrap_err(),
+            ParseError::Empty
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            input_to_grid::<char>("abc\nde\nfgh").unwrap_err(),
+            ParseError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+
+        let error = input_to_grid::<u8>("123\n4x6").unwrap_err();
+        assert!(matches!(
+            error,
+            ParseError::Cell {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "invalid cell at line 2, column 2: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_with_markers() {
+        let (grid, markers) =
+            parse_grid_with_markers("S.#\n.#E", &['S', 'E'], |c| Ok::<_, ()>(c == '#')).unwrap();
+
+        assert_eq!(grid.iter().filter(|&&wall| wall).count(), 2);
+        assert_eq!(markers[&'S'], [(0, 0)]);
+        assert_eq!(markers[&'E'], [(1, 2)]);
+    }
+
+    #[test]
+    fn test_parse_separated_grid() {
+        let grid = parse_separated_grid("22 13  7\n 8  2 23", ' ', str::parse::<u32>).unwrap();
+        assert_eq!(
+            grid.iter().copied().collect::<Vec<_>>(),
+            [22, 13, 7, 8, 2, 23]
+        );
+
+        assert!(matches!(
+            parse_separated_grid("1,2\n3,x", ',', str::parse::<u32>),
+            Err(ParseError::Cell {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use std::iter;
+
+use super::{CustomGrid, EdgePolicy};
+
+impl<T> CustomGrid<T> {
+    /// Walks from a cell in a `(row, col)` direction, e.g. `(-1, 1)` for up-right, yielding every
+    /// cell until the edge of the grid. The cell itself is not included.
+    pub fn iter_ray(
+        &self,
+        row: usize,
+        col: usize,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        // A ray without a direction would never leave the cell.
+        let start = match direction {
+            (0, 0) => None,
+            _ => self.offset(row, col, direction, EdgePolicy::Clip),
+        };
+
+        iter::successors(start, move |&(row, col)| {
+            self.offset(row, col, direction, EdgePolicy::Clip)
+        })
+        .map_while(|(row, col)| self.0.get(row, col).map(|val| ((row, col), val)))
+    }
+
+    /// Returns the first cell in a direction that equals `value`, see [`CustomGrid::iter_ray`].
+    pub fn first_in_ray(
+        &self,
+        row: usize,
+        col: usize,
+        direction: (isize, isize),
+        value: &T,
+    ) -> Option<((usize, usize), &T)>
+    where
+        T: PartialEq,
+    {
+        self.first_in_ray_by(row, col, direction, |val| val == value)
+    }
+
+    /// Returns the first cell in a direction that satisfies `predicate`, skipping the cells in
+    /// between, e.g. the first seat that can be seen across the floor.
+    pub fn first_in_ray_by(
+        &self,
+        row: usize,
+        col: usize,
+        direction: (isize, isize),
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Option<((usize, usize), &T)> {
+        self.iter_ray(row, col, direction)
+            .find(|(_, val)| predicate(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::input_to_grid;
+
+    #[test]
+    fn test_iter_ray() {
+        let grid = input_to_grid::<char>("abc\ndef\nghi").unwrap();
+
+        let diagonal: String = grid.iter_ray(0, 0, (1, 1)).map(|(_, c)| *c).collect();
+        assert_eq!(diagonal, "ei");
+
+        let left: Vec<_> = grid.iter_ray(1, 2, (0, -1)).map(|(pos, _)| pos).collect();
+        assert_eq!(left, [(1, 1), (1, 0)]);
+
+        assert_eq!(grid.iter_ray(0, 0, (-1, 0)).count(), 0);
+        assert_eq!(grid.iter_ray(1, 1, (0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_first_in_ray() {
+        let grid = input_to_grid::<char>("L.#.L\n.....\n#...L").unwrap();
+
+        assert_eq!(grid.first_in_ray(0, 0, (0, 1), &'L'), Some(((0, 4), &'L')));
+        assert_eq!(grid.first_in_ray(0, 0, (1, 0), &'L'), None);
+        assert_eq!(
+            grid.first_in_ray_by(0, 0, (0, 1), |&c| c != '.'),
+            Some(((0, 2), &'#'))
+        );
+        assert_eq!(grid.first_in_ray_by(0, 0, (1, 1), |&c| c != '.'), None);
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Formatter},
+    mem,
+};
+
+use super::{CustomGrid, Outcome};
+
+/// A position in a [`SparseGrid`] with `N` dimensions.
+pub type Point<const N: usize> = [isize; N];
+
+/// A grid with `N` dimensions that only stores the cells that are set, so it can grow in any
+/// direction, e.g. for simulations that spread out every step.
+#[derive(Clone)]
+pub struct SparseGrid<T, const N: usize> {
+    cells: HashMap<Point<N>, T>,
+    /// The inclusive corners of the box around all cells.
+    bounds: Option<(Point<N>, Point<N>)>,
+    /// The cells of the next generation, kept around to reuse their allocation.
+    next: HashMap<Point<N>, T>,
+    generation: usize,
+}
+
+/// The view of a cell that a rule of [`SparseGrid::step`] receives.
+pub struct SparseCell<'a, T, const N: usize> {
+    grid: &'a SparseGrid<T, N>,
+    point: Point<N>,
+    offsets: &'a [Point<N>],
+}
+
+impl<'a, T, const N: usize> SparseCell<'a, T, N> {
+    /// The value of the cell, [`None`] if it is not set.
+    pub fn value(&self) -> Option<&'a T> {
+        self.grid.get(self.point)
+    }
+
+    pub fn point(&self) -> Point<N> {
+        self.point
+    }
+
+    pub fn grid(&self) -> &'a SparseGrid<T, N> {
+        self.grid
+    }
+
+    /// The set cells of the `3^N - 1` surrounding cells.
+    pub fn neighbors(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.offsets
+            .iter()
+            .filter_map(|offset| self.grid.get(add(self.point, *offset)))
+    }
+
+    pub fn count_neighbors(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.neighbors().filter(|val| predicate(val)).count()
+    }
+}
+
+/// The offsets of the `3^N - 1` cells around a cell, along every axis and diagonal.
+pub fn neighbor_offsets<const N: usize>() -> Vec<Point<N>> {
+    let count = 3usize.pow(N as u32);
+
+    (0..count)
+        .map(|mut index| {
+            let mut offset = [0; N];
+            for axis in offset.iter_mut().rev() {
+                *axis = (index % 3) as isize - 1;
+                index /= 3;
+            }
+            offset
+        })
+        .filter(|offset| offset.iter().any(|&axis| axis != 0))
+        .collect()
+}
+
+fn add<const N: usize>(mut point: Point<N>, offset: Point<N>) -> Point<N> {
+    point
+        .iter_mut()
+        .zip(offset)
+        .for_each(|(axis, by)| *axis += by);
+    point
+}
+
+fn extend<const N: usize>(bounds: &mut Option<(Point<N>, Point<N>)>, point: Point<N>) {
+    let (min, max) = bounds.get_or_insert((point, point));
+    for axis in 0..N {
+        min[axis] = min[axis].min(point[axis]);
+        max[axis] = max[axis].max(point[axis]);
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for SparseGrid<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(&self.cells).finish()
+    }
+}
+
+/// Grids are equal if the same cells are set to the same values.
+impl<T: PartialEq, const N: usize> PartialEq for SparseGrid<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SparseGrid<T, N> {}
+
+impl<T, const N: usize> Default for SparseGrid<T, N> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+            next: HashMap::new(),
+            generation: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> SparseGrid<T, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places the cells of a 2D grid that satisfy `keep` at `[row, col, 0, ...]`.
+    pub fn from_layer(grid: &CustomGrid<T>, mut keep: impl FnMut(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        assert!(N >= 2, "a layer needs at least 2 dimensions");
+
+        let mut sparse = Self::new();
+        for ((row, col), val) in grid.indexed_iter() {
+            if keep(val) {
+                let mut point = [0; N];
+                point[0] = row as isize;
+                point[1] = col as isize;
+                sparse.insert(point, val.clone());
+            }
+        }
+        sparse
+    }
+
+    pub fn get(&self, point: Point<N>) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point<N>) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point<N>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn insert(&mut self, point: Point<N>, val: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.cells.insert(point, val)
+    }
+
+    pub fn remove(&mut self, point: Point<N>) -> Option<T> {
+        let val = self.cells.remove(&point)?;
+
+        let on_edge = self.bounds.is_some_and(|(min, max)| {
+            (0..N).any(|axis| point[axis] == min[axis] || point[axis] == max[axis])
+        });
+        if on_edge {
+            self.recompute_bounds();
+        }
+
+        Some(val)
+    }
+
+    /// The number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<N>, &T)> {
+        self.cells.iter()
+    }
+
+    /// The number of steps applied so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The inclusive `(min, max)` corners of the smallest box around all cells.
+    pub fn bounds(&self) -> Option<(Point<N>, Point<N>)> {
+        self.bounds
+    }
+
+    /// The set cells around a point, see [`neighbor_offsets`].
+    pub fn neighbors(&self, point: Point<N>) -> impl Iterator<Item = (Point<N>, &T)> {
+        neighbor_offsets::<N>()
+            .into_iter()
+            .filter_map(move |offset| {
+                let neighbor = add(point, offset);
+                self.get(neighbor).map(|val| (neighbor, val))
+            })
+    }
+
+    fn extend_bounds(&mut self, point: Point<N>) {
+        extend(&mut self.bounds, point);
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = None;
+        for point in self.cells.keys() {
+            extend(&mut self.bounds, *point);
+        }
+    }
+}
+
+impl<T: PartialEq, const N: usize> SparseGrid<T, N> {
+    /// Applies `rule` at once to every set cell and every cell next to one, which are the only
+    /// cells that can change. Cells for which `rule` returns [`None`] are unset. Returns whether
+    /// any cell changed.
+    pub fn step(&mut self, mut rule: impl FnMut(&SparseCell<T, N>) -> Option<T>) -> bool {
+        let offsets = neighbor_offsets::<N>();
+
+        let mut next = mem::take(&mut self.next);
+        next.clear();
+
+        let candidates: HashSet<Point<N>> = self
+            .cells
+            .keys()
+            .flat_map(|point| {
+                let point = *point;
+                offsets
+                    .iter()
+                    .map(move |offset| add(point, *offset))
+                    .chain([point])
+            })
+            .collect();
+
+        for point in candidates {
+            let cell = SparseCell {
+                grid: self,
+                point,
+                offsets: &offsets,
+            };
+            if let Some(val) = rule(&cell) {
+                next.insert(point, val);
+            }
+        }
+
+        let changed = next != self.cells;
+
+        self.next = mem::replace(&mut self.cells, next);
+        self.recompute_bounds();
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Steps until the grid stops changing, or `limit` steps have been applied in total.
+    pub fn run(
+        &mut self,
+        limit: Option<usize>,
+        mut rule: impl FnMut(&SparseCell<T, N>) -> Option<T>,
+    ) -> Outcome {
+        loop {
+            if limit.is_some_and(|limit| self.generation >= limit) {
+                return Outcome::StepLimit {
+                    generation: self.generation,
+                };
+            }
+
+            if !self.step(&mut rule) {
+                return Outcome::FixedPoint {
+                    generation: self.generation - 1,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::input_to_grid;
+
+    /// The Conway Cubes rule of 2020 day 17, only active cubes are set.
+    fn conway_cubes<const N: usize>(cell: &SparseCell<char, N>) -> Option<char> {
+        match (cell.value(), cell.neighbors().count()) {
+            (Some(_), 2 | 3) | (None, 3) => Some('#'),
+            _ => None,
+        }
+    }
+
+    fn cubes<const N: usize>() -> SparseGrid<char, N> {
+        let grid = input_to_grid(".#.\n..#\n###").unwrap();
+        SparseGrid::from_layer(&grid, |&c| c == '#')
+    }
+
+    #[test]
+    fn test_neighbor_offsets() {
+        assert_eq!(neighbor_offsets::<1>(), [[-1], [1]]);
+        assert_eq!(neighbor_offsets::<2>().len(), 8);
+        assert_eq!(neighbor_offsets::<3>().len(), 26);
+        assert_eq!(neighbor_offsets::<4>().len(), 80);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid: SparseGrid<char, 3> = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert([0, 0, 0], 'a');
+        grid.insert([2, -1, 4], 'b');
+        grid.insert([1, 1, 1], 'c');
+        assert_eq!(grid.bounds(), Some(([0, -1, 0], [2, 1, 4])));
+
+        grid.remove([2, -1, 4]);
+        assert_eq!(grid.bounds(), Some(([0, 0, 0], [1, 1, 1])));
+        assert_eq!(grid.neighbors([0, 0, 1]).count(), 2);
+    }
+
+    #[test]
+    fn test_conway_cubes() {
+        let mut cubes_3d = cubes::<3>();
+        assert_eq!(cubes_3d.len(), 5);
+        assert_eq!(cubes_3d.bounds(), Some(([0, 0, 0], [2, 2, 0])));
+
+        assert_eq!(
+            cubes_3d.run(Some(3), conway_cubes),
+            Outcome::StepLimit { generation: 3 }
+        );
+        assert_eq!(
+            cubes_3d.run(Some(6), conway_cubes),
+            Outcome::StepLimit { generation: 6 }
+        );
+        assert_eq!(cubes_3d.generation(), 6);
+        assert_eq!(cubes_3d.len(), 112);
+
+        let mut cubes_4d = cubes::<4>();
+        cubes_4d.run(Some(6), conway_cubes);
+        assert_eq!(cubes_4d.len(), 848);
+    }
+}
@@ -1,14 +1,27 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify, watch,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
+use advent_of_code::template::Puzzle;
 #[cfg(feature = "today")]
 use std::process;
 
+/// Every solution in `src/bin`, compiled into this binary by `build.rs`.
+#[allow(dead_code)]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::scaffold,
+        runner::{parse_samples, BenchConfig, OutputFormat},
+        Day, Year,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -17,62 +30,143 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            block: Option<usize>,
+            write: bool,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            dry_run: bool,
+            template: String,
+            answer_type: String,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            force: bool,
+            record: bool,
+            watch: bool,
+            format: OutputFormat,
+            timeout: Option<Duration>,
+            input: Option<String>,
         },
         All {
-            release: bool,
+            format: OutputFormat,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            format: OutputFormat,
+            bench: BenchConfig,
+            compare_threshold: Option<f64>,
+        },
+        Verify {
+            day: Option<Day>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the year it applies to. The year is passed with `--year` and
+    /// defaults to the `AOC_YEAR` environment variable.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+        let command = args.subcommand()?;
+
+        let year = match args.opt_value_from_str::<_, Year>("--year")? {
+            Some(year) => year,
+            None => Year::from_env()
+                .ok_or("no year specified, pass `--year` or set the `AOC_YEAR` variable")?,
+        };
+
+        let app_args = match command.as_deref() {
+            Some("all") => {
+                // solutions run in-process, in the build of this binary. The `all` alias builds it
+                // with `--release`, the flag is only accepted so existing invocations keep working.
+                args.contains("--release");
+
+                AppArguments::All {
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    timeout: parse_timeout(&mut args)?,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+
+                let mut bench = BenchConfig::default();
+                if let Some(millis) = args.opt_value_from_str("--budget")? {
+                    bench.budget = Duration::from_millis(millis);
+                }
+                bench.samples = args.opt_value_from_fn("--samples", parse_samples)?;
+
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+
+                // a timed out part cannot be stopped and would skew the remaining benchmarks.
+                if parse_timeout(&mut args)?.is_some() {
+                    return Err("`--timeout` cannot be combined with `time`".into());
+                }
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    format,
+                    bench,
+                    compare_threshold: compare.then_some(threshold),
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                day: args.opt_free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+                block: args.opt_value_from_str("--block")?,
+                write: args.contains("--write"),
+                overwrite: args.contains("--overwrite"),
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                dry_run: args.contains("--dry-run"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
+                answer_type: args
+                    .opt_value_from_str("--type")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_ANSWER_TYPE.into()),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
                 dhat: args.contains("--dhat"),
+                record: args.contains("--record"),
+                watch: args.contains("--watch"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                timeout: parse_timeout(&mut args)?,
+                input: args.opt_value_from_str("--input")?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -86,12 +180,44 @@ mod args {
             }
         };
 
+        // watching reruns the tests and the solution with their default options.
+        if let AppArguments::Solve {
+            watch: true,
+            dhat,
+            submit,
+            record,
+            format,
+            timeout,
+            input,
+            ..
+        } = &app_args
+        {
+            let is_combined = *dhat
+                || submit.is_some()
+                || *record
+                || *format != OutputFormat::Text
+                || timeout.is_some()
+                || input.is_some();
+
+            if is_combined {
+                return Err("`--watch` can only be combined with `--release`".into());
+            }
+        }
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, pico_args::Error> {
+        Ok(args
+            .opt_value_from_str("--timeout")?
+            .map(Duration::from_millis))
     }
 }
 
@@ -101,19 +227,52 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                format,
+                jobs,
+                timeout,
+            } => all::handle(solutions::SOLUTIONS, year, format, jobs, timeout),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                format,
+                bench,
+                compare_threshold,
+            } => time::handle(
+                solutions::SOLUTIONS,
+                year,
+                day,
+                all,
+                store,
+                format,
+                bench,
+                compare_threshold,
+            ),
+            AppArguments::Verify { day } => verify::handle(solutions::SOLUTIONS, year, day),
+            AppArguments::Download { day } => download::handle(Puzzle::new(year, day)),
+            AppArguments::Read { day } => read::handle(Puzzle::new(year, day)),
+            AppArguments::Examples {
+                day,
+                block,
+                write,
+                overwrite,
+            } => {
+                examples::handle(Puzzle::new(year, day), block, write, overwrite);
+            }
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                dry_run,
+                template,
+                answer_type,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                let puzzle = Puzzle::new(year, day);
+                scaffold::handle(puzzle, overwrite, dry_run, &template, &answer_type);
+                if download && !dry_run {
+                    download::handle(puzzle);
                 }
             }
             AppArguments::Solve {
@@ -121,14 +280,36 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                force,
+                record,
+                watch,
+                format,
+                timeout,
+                input,
+            } => {
+                let puzzle = Puzzle::new(year, day);
+                if watch {
+                    watch::handle(puzzle, release);
+                } else {
+                    solve::handle(
+                        puzzle, release, dhat, submit, force, record, format, timeout, input,
+                    );
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        let puzzle = Puzzle::new(year, day);
+                        scaffold::handle(
+                            puzzle,
+                            false,
+                            false,
+                            scaffold::DEFAULT_TEMPLATE,
+                            scaffold::DEFAULT_ANSWER_TYPE,
+                        );
+                        download::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
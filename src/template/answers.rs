@@ -0,0 +1,301 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Puzzle, Year};
+
+/// Path of the answers file of a year, e.g. `data/answers.json`.
+fn file_path(year: Year) -> PathBuf {
+    year.data_dir().join("answers.json")
+}
+
+/// Represents the accepted answers of a single day and input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    /// Name of the input variant, `None` for the default input.
+    pub input: Option<String>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let path = file_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file(year: Year) -> Self {
+        let Ok(s) = fs::read_to_string(file_path(year)) else {
+            return Answers::default();
+        };
+
+        match Answers::try_from(s) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{e}");
+                Answers::default()
+            }
+        }
+    }
+
+    /// Returns the accepted answer for a part, if known.
+    pub fn get(&self, day: Day, input: Option<&str>, part: u8) -> Option<&str> {
+        let answer = self
+            .data
+            .iter()
+            .find(|a| a.day == day && a.input.as_deref() == input)?;
+
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the accepted answer for a part, overwriting a previous one.
+    pub fn set(&mut self, day: Day, input: Option<&str>, part: u8, value: &str) {
+        let position = self
+            .data
+            .iter()
+            .position(|a| a.day == day && a.input.as_deref() == input);
+
+        let index = match position {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    input: input.map(str::to_string),
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.len() - 1
+            }
+        };
+
+        let answer = &mut self.data[index];
+
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+
+        self.data
+            .sort_unstable_by(|a, b| (a.day, &a.input).cmp(&(b.day, &b.input)));
+    }
+
+    /// Records the accepted answer for a part in the answers file of the puzzle's year.
+    /// Returns whether the answer was not recorded before.
+    pub fn record(
+        puzzle: Puzzle,
+        input: Option<&str>,
+        part: u8,
+        value: &str,
+    ) -> Result<bool, Error> {
+        let Puzzle { year, day } = puzzle;
+        let mut answers = Answers::read_from_file(year);
+
+        if answers.get(day, input, part) == Some(value) {
+            return Ok(false);
+        }
+
+        answers.set(day, input, part, value);
+        answers.store_file(year)?;
+
+        Ok(true)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "input".into(),
+            match &value.input {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_1".into(),
+            match &value.part_1 {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_2".into(),
+            match &value.part_2 {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        // the input is optional, answers recorded before variants were supported lack it.
+        let input = match json.get("input") {
+            Some(JsonValue::String(x)) => Some(x.clone()),
+            Some(JsonValue::Null) | None => None,
+            _ => return Err("Expected answer.input to be null or string.".into()),
+        };
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            input,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::Answers;
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "42", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), None, 1), Some("42"));
+        assert_eq!(answers.get(day!(1), None, 2), None);
+        assert_eq!(answers.get(day!(2), None, 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01" }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn sets_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), None, 2, "336");
+        answers.set(day!(1), None, 1, "42");
+        answers.set(day!(3), None, 1, "7");
+        answers.set(day!(3), None, 1, "8");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(day!(3), None, 1), Some("8"));
+        assert_eq!(answers.get(day!(3), None, 2), Some("336"));
+    }
+
+    #[test]
+    fn sets_answers_per_input() {
+        let mut answers = Answers::default();
+        answers.set(day!(7), Some("bob"), 1, "3");
+        answers.set(day!(7), None, 1, "1");
+        answers.set(day!(7), Some("alice"), 1, "2");
+
+        assert_eq!(answers.data.len(), 3);
+        assert_eq!(answers.data[0].input, None);
+        assert_eq!(answers.data[1].input.as_deref(), Some("alice"));
+        assert_eq!(answers.get(day!(7), None, 1), Some("1"));
+        assert_eq!(answers.get(day!(7), Some("alice"), 1), Some("2"));
+        assert_eq!(answers.get(day!(7), Some("carol"), 1), None);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), None, 1, "7");
+        answers.set(day!(3), Some("alice"), 1, "8");
+
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(3), None, 1), Some("7"));
+        assert_eq!(answers.get(day!(3), Some("alice"), 1), Some("8"));
+    }
+}
@@ -0,0 +1,501 @@
+/// Minimal client for the Advent of Code website.
+/// Authenticates with the session cookie stored in the `.session` file.
+use std::{env, fmt::Display, fs, io, time::Duration};
+
+use crate::template::{Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE_PATH: &str = ".session";
+static USER_AGENT: &str = "github.com/aBMania/advent-of-code-2020";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    UnexpectedResponse(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "session cookie not found. Paste it into the \"{SESSION_FILE_PATH}\" file."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::UnexpectedResponse(text) => {
+                write!(f, "could not understand the response: {text}")
+            }
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// Outcome of submitting an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently. `wait` is the remaining cooldown, if stated.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    AlreadySolved,
+}
+
+impl SubmissionOutcome {
+    /// Interprets the text of a submission response.
+    pub fn parse(text: &str) -> Option<Self> {
+        if text.contains("That's the right answer") {
+            Some(SubmissionOutcome::Correct)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Some(SubmissionOutcome::TooHigh)
+            } else if text.contains("too low") {
+                Some(SubmissionOutcome::TooLow)
+            } else {
+                Some(SubmissionOutcome::Wrong)
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Some(SubmissionOutcome::RateLimited {
+                wait: parse_wait(text),
+            })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(SubmissionOutcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the cooldown of a response like `You have 1m 5s left to wait.`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let len = text[start..].find(" left to wait")?;
+
+    text[start..start + len]
+        .split_whitespace()
+        .map(|part| {
+            if let Some(x) = part.strip_suffix('m') {
+                x.parse::<u64>().ok().map(|x| x * 60)
+            } else {
+                part.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "⭐ That's the right answer!"),
+            SubmissionOutcome::TooHigh => write!(f, "✖ Wrong answer: too high."),
+            SubmissionOutcome::TooLow => write!(f, "✖ Wrong answer: too low."),
+            SubmissionOutcome::Wrong => write!(f, "✖ Wrong answer."),
+            SubmissionOutcome::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "⏳ Answered too recently, wait {wait:?} before submitting again."
+            ),
+            SubmissionOutcome::RateLimited { wait: None } => {
+                write!(f, "⏳ Answered too recently, wait before submitting again.")
+            }
+            SubmissionOutcome::AlreadySolved => {
+                write!(f, "✔ This part has already been solved.")
+            }
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: u16,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Creates a client for `year` from the `.session` file.
+    /// The base url defaults to the Advent of Code website and can be overridden with `AOC_BASE_URL`.
+    pub fn for_year(year: Year) -> Result<Self, AocClientError> {
+        let session =
+            fs::read_to_string(SESSION_FILE_PATH).map_err(|_| AocClientError::SessionNotFound)?;
+
+        if session.trim().is_empty() {
+            return Err(AocClientError::SessionNotFound);
+        }
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(AocClient::new(&base_url, &session, year.into_inner()))
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        Ok(self.get(&url).call()?.into_string()?)
+    }
+
+    /// Fetches the puzzle description of a day as markdown.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day)).call()?.into_string()?;
+        Ok(html_to_markdown(&html))
+    }
+
+    /// Submits an answer and interprets the response.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        let text = html_to_text(&html);
+        SubmissionOutcome::parse(&text).ok_or(AocClientError::UnexpectedResponse(text))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> ureq::Request {
+        self.agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the contents of all `<article>` elements, or the whole document if there are none.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len..];
+    }
+
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    articles
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Splits html into tags and text. Tags are returned with their name, lowercased and
+/// prefixed by `/` for closing tags, and their raw attributes.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let Some(end) = rest.find('>') else {
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let tag = &rest[1..end];
+                let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Tag(
+                    name.trim_end_matches('/').to_ascii_lowercase(),
+                    attributes,
+                ));
+                rest = &rest[end + 1..];
+            }
+            Some(i) => {
+                tokens.push(Token::Text(&rest[..i]));
+                rest = &rest[i..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                break;
+            }
+        }
+    }
+
+    tokens
+}
+
+enum Token<'a> {
+    Tag(String, &'a str),
+    Text(&'a str),
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = attributes[start..].find('"')?;
+    Some(&attributes[start..start + len])
+}
+
+/// Converts the puzzle articles of an html page into markdown.
+/// Only handles the subset of html that is used in puzzle descriptions.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+
+    for article in articles(html) {
+        // emphasized code is rendered as `*`code`*` so it stays readable in markdown.
+        let article = article
+            .replace("<code><em>", "<em><code>")
+            .replace("</em></code>", "</code></em>");
+
+        let mut in_pre = false;
+        let mut link: Option<String> = None;
+
+        for token in tokenize(&article) {
+            match token {
+                Token::Text(text) => {
+                    // whitespace between block elements is dropped, spaces between inline ones kept.
+                    if in_pre || !text.trim().is_empty() || !text.contains('\n') {
+                        out.push_str(&decode_entities(text));
+                    }
+                }
+                Token::Tag(name, attributes) => match name.as_str() {
+                    "pre" => {
+                        in_pre = true;
+                        out.push_str("```\n");
+                    }
+                    "/pre" => {
+                        in_pre = false;
+                        if !out.ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str("```\n\n");
+                    }
+                    _ if in_pre => {}
+                    "h2" => out.push_str("## "),
+                    "/h2" | "/p" => out.push_str("\n\n"),
+                    "li" => out.push_str("- "),
+                    "/li" if !out.ends_with('\n') => out.push('\n'),
+                    "/ul" => out.push('\n'),
+                    "code" | "/code" => out.push('`'),
+                    "em" | "/em" => out.push('*'),
+                    "a" => {
+                        link = attribute(attributes, "href").map(str::to_string);
+                        out.push('[');
+                    }
+                    "/a" => {
+                        out.push_str(&format!("]({})", link.take().unwrap_or_default()));
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+/// Strips all tags from the articles of an html page.
+pub fn html_to_text(html: &str) -> String {
+    articles(html)
+        .into_iter()
+        .flat_map(tokenize)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            Token::Tag(..) => None,
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use std::time::Duration;
+
+    use super::{html_to_markdown, html_to_text, AocClient, SubmissionOutcome};
+    use crate::day;
+
+    /// Serves a single request with `body` and returns the raw request.
+    fn mock_server(body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 4096];
+
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some(head_end) = text.find("\r\n\r\n") {
+                    let content_length = text
+                        .lines()
+                        .find_map(|l| {
+                            l.to_ascii_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(str::to_string)
+                        })
+                        .and_then(|l| l.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if request.len() >= head_end + 4 + content_length {
+                        break;
+                    }
+                }
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (url, server) = mock_server("1\n2\n3\n");
+        let client = AocClient::new(&url, "secret\n", 2020);
+
+        assert_eq!(client.input(day!(1)).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2020/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=secret\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = mock_server(
+            "<html><main><article><p>That's the right answer! <a href=\"/2020\">[Return]</a></p></article></main></html>",
+        );
+        let client = AocClient::new(&url, "secret", 2020);
+
+        assert_eq!(
+            client.submit(day!(7), 2, "42").unwrap(),
+            SubmissionOutcome::Correct
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2020/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn converts_puzzles_to_markdown() {
+        let html = "<body><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>\n<p>Find <em>two</em> entries in <a href=\"/2020/day/1/input\">your input</a>:</p>\n<pre><code>1721\n979 &lt; 1000\n</code></pre>\n<ul>\n<li>The answer is <code><em>514579</em></code>.</li>\n</ul>\n</article></body>";
+
+        assert_eq!(
+            html_to_markdown(html),
+            [
+                "## --- Day 1: Test ---",
+                "",
+                "Find *two* entries in [your input](/2020/day/1/input):",
+                "",
+                "```",
+                "1721",
+                "979 < 1000",
+                "```",
+                "",
+                "- The answer is *`514579`*.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn parses_submission_outcomes() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", SubmissionOutcome::Correct),
+            ("That's not the right answer; your answer is too high. Please wait one minute before trying again.", SubmissionOutcome::TooHigh),
+            ("That's not the right answer; your answer is too low.", SubmissionOutcome::TooLow),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data.", SubmissionOutcome::Wrong),
+            ("You don't seem to be solving the right level.  Did you already complete it?", SubmissionOutcome::AlreadySolved),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.", SubmissionOutcome::RateLimited { wait: Some(Duration::from_secs(34)) }),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.", SubmissionOutcome::RateLimited { wait: Some(Duration::from_secs(65)) }),
+            ("You gave an answer too recently.", SubmissionOutcome::RateLimited { wait: None }),
+        ];
+
+        for (text, outcome) in cases {
+            assert_eq!(SubmissionOutcome::parse(text), Some(outcome));
+        }
+
+        assert_eq!(SubmissionOutcome::parse("Internal server error"), None);
+    }
+
+    #[test]
+    fn converts_responses_to_text() {
+        let html =
+            "<article><p>That's not the right answer; your answer is too high.</p></article>";
+        assert_eq!(
+            html_to_text(html),
+            "That's not the right answer; your answer is too high."
+        );
+    }
+}
@@ -1,5 +1,21 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::runner::{OutputFormat, RunOptions};
+use crate::template::{all_days, run_multi::run_multi, Solution, Year};
+
+pub fn handle(
+    solutions: &[&dyn Solution],
+    year: Year,
+    format: OutputFormat,
+    jobs: usize,
+    timeout: Option<Duration>,
+) {
+    let options = RunOptions {
+        is_timed: false,
+        format,
+        jobs,
+        timeout,
+        ..RunOptions::default()
+    };
+    run_multi(solutions, year, &all_days().collect(), &options);
 }
@@ -1,14 +1,40 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::aoc_client::AocClient;
+use crate::template::Puzzle;
+use std::{fs, path::Path, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: Puzzle) {
+    let input_path = puzzle.data_file("inputs", ".txt");
+    let puzzle_path = puzzle.data_file("puzzles", ".md");
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let result = AocClient::for_year(puzzle.year).and_then(|client| {
+        let input = client.input(puzzle.day)?;
+        write_file(&input_path, &input)?;
+        let text = client.puzzle(puzzle.day)?;
+        write_file(&puzzle_path, &text)?;
+        Ok(())
+    });
+
+    if let Err(e) = result {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+}
+
+/// Writes a file, creating its directory first. Data directories of other years than the
+/// default year usually do not exist yet.
+pub fn write_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
 }
@@ -0,0 +1,107 @@
+use std::{fs, process};
+
+use crate::template::commands::download::write_file;
+use crate::template::examples::{fill_in_answer, PuzzleExamples};
+use crate::template::{Puzzle, ANSI_BOLD, ANSI_RESET};
+
+/// Propose an example input and expected answers from the downloaded puzzle description.
+/// With `write`, the proposed block is written to the example file and the answers are filled
+/// in the tests of the solution. An example file with contents is only replaced with `overwrite`.
+pub fn handle(puzzle: Puzzle, block: Option<usize>, write: bool, overwrite: bool) {
+    let puzzle_path = puzzle.data_file("puzzles", ".md");
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{}\". Download the puzzle first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    };
+
+    let examples = PuzzleExamples::extract(&markdown);
+
+    if examples.blocks.is_empty() {
+        eprintln!("The puzzle description does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    for (index, code_block) in examples.blocks.iter().enumerate() {
+        println!(
+            "{ANSI_BOLD}Block {} (part {}){ANSI_RESET}",
+            index + 1,
+            code_block.part
+        );
+        print!("{}", code_block.text);
+        println!();
+    }
+
+    for (part, answer) in (1..=2).zip(&examples.answers) {
+        if let Some(answer) = answer {
+            println!("{ANSI_BOLD}Part {part}:{ANSI_RESET} {answer}");
+        }
+    }
+
+    let index = block.unwrap_or(1);
+    let Some(code_block) = index.checked_sub(1).and_then(|i| examples.blocks.get(i)) else {
+        eprintln!("There is no block {index}.");
+        process::exit(1);
+    };
+
+    let example_path = puzzle.data_file("examples", ".txt");
+    let module_path = puzzle.bin_path();
+
+    if !write {
+        println!("---");
+        println!(
+            "🎄 Type `cargo examples {} --write` to write block {index} to \"{}\" and fill in the answers in \"{}\". Pick another block with `--block <n>`.",
+            puzzle.day,
+            example_path.display(),
+            module_path.display()
+        );
+        return;
+    }
+
+    let has_contents = fs::metadata(&example_path).is_ok_and(|metadata| metadata.len() > 0);
+    if has_contents && !overwrite {
+        eprintln!(
+            "The example file \"{}\" already exists, pass `--overwrite` to replace it.",
+            example_path.display()
+        );
+        process::exit(1);
+    }
+
+    match write_file(&example_path, &code_block.text) {
+        Ok(()) => println!("Wrote block {index} to \"{}\"", example_path.display()),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        eprintln!(
+            "Could not read \"{}\", scaffold the day first.",
+            module_path.display()
+        );
+        process::exit(1);
+    };
+
+    for (part, answer) in (1..=2).zip(&examples.answers) {
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        match fill_in_answer(&module, part, answer) {
+            Some(filled) => {
+                module = filled;
+                println!("Filled in the answer of part {part}: {answer}");
+            }
+            None => println!("Skipped part {part}, its test does not assert `None`."),
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
@@ -1,15 +1,21 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client::AocClient, commands::download::write_file, Puzzle};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: Puzzle) {
+    let puzzle_path = puzzle.data_file("puzzles", ".md");
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let result = AocClient::for_year(puzzle.year).and_then(|client| {
+        let text = client.puzzle(puzzle.day)?;
+        write_file(&puzzle_path, &text)?;
+        Ok(text)
+    });
+
+    match result {
+        Ok(text) => println!("{text}"),
+        Err(e) => {
+            eprintln!("failed to read puzzle: {e}");
+            process::exit(1);
+        }
     };
 }
@@ -1,15 +1,20 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Puzzle, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Directory holding the module templates, one `<name>.txt` file per template.
+const TEMPLATES_DIR: &str = "src/templates";
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+pub const DEFAULT_TEMPLATE: &str = "default";
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
+    create_dir(path)?;
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,61 +24,179 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// What scaffolding does with a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    Create,
+    Overwrite,
+    Skip,
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Existing files are never touched, except for the module if `overwrite` is set.
+fn plan(path: &Path, overwrite: bool) -> Action {
+    match (path.exists(), overwrite) {
+        (false, _) => Action::Create,
+        (true, true) => Action::Overwrite,
+        (true, false) => Action::Skip,
+    }
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+/// Creates or overwrites a file according to `action`, or only reports it on a dry run.
+fn apply(path: &Path, contents: &str, label: &str, action: Action, dry_run: bool) {
+    let path_str = path.display();
+    let empty = if contents.is_empty() { "empty " } else { "" };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    match (action, dry_run) {
+        (Action::Create, true) => println!("Would create {empty}{label} \"{path_str}\""),
+        (Action::Overwrite, true) => println!("Would overwrite {label} \"{path_str}\""),
+        (Action::Skip, true) => println!("Would skip {label} \"{path_str}\", it already exists"),
+        (Action::Skip, false) => println!("Skipped {label} \"{path_str}\", it already exists"),
+        (_, false) => {
+            let result = safe_create_file(path, action == Action::Overwrite)
+                .and_then(|mut file| file.write_all(contents.as_bytes()));
+
+            match (result, action) {
+                (Ok(()), Action::Overwrite) => println!("Overwrote {label} \"{path_str}\""),
+                (Ok(()), _) => println!("Created {empty}{label} \"{path_str}\""),
+                (Err(e), _) => {
+                    eprintln!("Failed to create {label} \"{path_str}\": {e}");
+                    process::exit(1);
+                }
+            }
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+/// Creates the directory of a file. Data directories of other years than the default year
+/// usually do not exist yet.
+fn create_dir(path: &Path) -> Result<(), std::io::Error> {
+    match path.parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+/// Names of the templates in the templates directory, sorted alphabetically.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+
+    names.sort_unstable();
+    names
+}
+
+/// Fills in the placeholders of a module template:
+///  - `%DAY_NUMBER%`: the day without padding, e.g. `7`.
+///  - `%DAY%`: the zero-padded day, e.g. `07`.
+///  - `%YEAR%`: the year of the puzzle, e.g. `2020`.
+///  - `%YEAR_ARG%`: the `year` argument of the `solution!` macro, empty for [`Year::FLAT`].
+///  - `%TYPE%`: the answer type of both parts, e.g. `u64`.
+fn render(template: &str, puzzle: Puzzle, answer_type: &str) -> String {
+    let year_arg = if puzzle.year.is_flat() {
+        String::new()
+    } else {
+        format!(", year = {}", puzzle.year)
+    };
+
+    template
+        .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
+        .replace("%DAY%", &puzzle.day.to_string())
+        .replace("%YEAR_ARG%", &year_arg)
+        .replace("%YEAR%", &puzzle.year.to_string())
+        .replace("%TYPE%", answer_type)
+}
+
+/// Creates the module, input and example file of a puzzle. Files that exist are skipped, only
+/// the module is replaced if `overwrite` is set. A dry run reports the planned actions only.
+pub fn handle(puzzle: Puzzle, overwrite: bool, dry_run: bool, template: &str, answer_type: &str) {
+    let input_path = puzzle.data_file("inputs", ".txt");
+    let example_path = puzzle.data_file("examples", ".txt");
+    let module_path = puzzle.bin_path();
+
+    let template_path = Path::new(TEMPLATES_DIR).join(format!("{template}.txt"));
+    let Ok(module_template) = fs::read_to_string(&template_path) else {
+        eprintln!(
+            "Unknown template `{template}`, available templates: {}.",
+            template_names().join(", ")
+        );
+        process::exit(1);
+    };
+
+    let module = render(&module_template, puzzle, answer_type);
+
+    let files = [
+        (module_path, module.as_str(), "module file", overwrite),
+        (input_path, "", "input file", false),
+        (example_path, "", "example file", false),
+    ];
+
+    for (path, contents, label, overwrite) in files {
+        apply(&path, contents, label, plan(&path, overwrite), dry_run);
+    }
+
+    if dry_run {
+        return;
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if Year::from_env() == Some(puzzle.year) {
+        println!("🎄 Type `cargo solve {}` to run your solution.", puzzle.day);
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            puzzle.day, puzzle.year
+        );
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{plan, render, Action};
+    use crate::{
+        day,
+        template::{Puzzle, Year},
+    };
+
+    #[test]
+    fn renders_placeholders() {
+        let template = "solution!(%DAY_NUMBER%%YEAR_ARG%); // %YEAR%-%DAY%\nOption<%TYPE%>";
+
+        let default = Puzzle::from(day!(7));
+        assert_eq!(
+            render(template, default, "u64"),
+            format!("solution!(7); // {}-07\nOption<u64>", default.year)
+        );
+
+        let other = Puzzle::new(Year::new(default.year.into_inner() + 1).unwrap(), day!(7));
+        assert_eq!(
+            render(template, other, "String"),
+            format!(
+                "solution!(7, year = {0}); // {0}-07\nOption<String>",
+                other.year
+            )
+        );
+    }
+
+    #[test]
+    fn plans_to_keep_existing_files() {
+        let dir = env::temp_dir().join("aoc-scaffold-plan");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("01.txt");
+        assert_eq!(plan(&path, false), Action::Create);
+        assert_eq!(plan(&path, true), Action::Create);
+
+        fs::write(&path, "real input").unwrap();
+        assert_eq!(plan(&path, false), Action::Skip);
+        assert_eq!(plan(&path, true), Action::Overwrite);
+    }
 }
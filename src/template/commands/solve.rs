@@ -1,9 +1,34 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
+use std::str::FromStr;
+use std::time::Duration;
 
-use crate::template::Day;
+use tinyjson::JsonValue;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use crate::template::inputs::{inputs_for, Input};
+use crate::template::run_multi::{print_inputs_table, DayResult};
+use crate::template::runner::{
+    print_message, print_part_result, record_answer, OutputFormat, PartResult,
+};
+use crate::template::Puzzle;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    force: bool,
+    record: bool,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+    input: Option<String>,
+) {
+    if input.is_some() && (submit_part.is_some() || record) {
+        eprintln!("`--input` cannot be combined with `--submit` or `--record`.");
+        process::exit(1);
+    }
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -18,9 +43,49 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_millis().to_string());
+    }
+
+    if input.is_none() {
+        let inputs = inputs_for(puzzle);
+
+        if inputs.iter().any(|input| input.name.is_some()) {
+            run_inputs(
+                &cmd_args,
+                puzzle,
+                &inputs,
+                submit_part,
+                force,
+                record,
+                format,
+            );
+            return;
+        }
+    }
+
+    if record {
+        cmd_args.push("--record".to_string());
+    }
+
+    if format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if force {
+            cmd_args.push("--force".to_string());
+        }
     }
 
     let mut cmd = Command::new("cargo")
@@ -32,3 +97,82 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Run the solution once per input and print the results as one table.
+/// Answers are submitted for the default input only, but recorded for every input.
+fn run_inputs(
+    cmd_args: &[String],
+    puzzle: Puzzle,
+    inputs: &[Input],
+    submit_part: Option<u8>,
+    force: bool,
+    record: bool,
+    format: OutputFormat,
+) {
+    let mut results = vec![];
+
+    for input in inputs {
+        let mut args = cmd_args.to_vec();
+        args.extend([
+            "--format".to_string(),
+            "json".to_string(),
+            "--input".to_string(),
+            input.path.to_string_lossy().to_string(),
+        ]);
+
+        if let (None, Some(submit_part)) = (&input.name, submit_part) {
+            args.push("--submit".to_string());
+            args.push(submit_part.to_string());
+
+            if force {
+                args.push("--force".to_string());
+            }
+        }
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()
+            .unwrap();
+
+        let mut parts = vec![];
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let part = JsonValue::from_str(line)
+                .ok()
+                .and_then(|json| PartResult::try_from(&json).ok());
+
+            match part {
+                Some(mut part) => {
+                    part.input.clone_from(&input.name);
+                    parts.push(part);
+                }
+                // e.g. the output of the solution itself.
+                None => print_message(format, line),
+            }
+        }
+
+        if record {
+            for part in &parts {
+                if let Some(answer) = &part.answer {
+                    record_answer(puzzle, input.name.as_deref(), part.part, answer, format);
+                }
+            }
+        }
+
+        results.push(DayResult {
+            day: puzzle.day,
+            input: input.name.clone(),
+            parts,
+        });
+    }
+
+    match format {
+        OutputFormat::Text => print_inputs_table(&results),
+        OutputFormat::Json => {
+            for part in results.iter().flat_map(|result| &result.parts) {
+                print_part_result(part, format);
+            }
+        }
+    }
+}
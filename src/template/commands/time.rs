@@ -1,15 +1,101 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{BenchConfig, OutputFormat, RunOptions};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, Solution, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[&dyn Solution],
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    format: OutputFormat,
+    bench: BenchConfig,
+    compare_threshold: Option<f64>,
+) {
+    let stored_timings = Timings::read_from_file(year);
 
-    let days_to_run = day.map_or_else(
+    // comparing needs the days that are fully benched, they are the ones with a baseline.
+    let days_to_run = days_to_run(&stored_timings, day, run_all, compare_threshold.is_some());
+
+    let options = RunOptions {
+        is_timed: true,
+        format,
+        bench,
+        ..RunOptions::default()
+    };
+
+    let timings = run_multi(solutions, year, &days_to_run, &options).unwrap();
+
+    let regressions = compare_threshold.map(|threshold| {
+        let regressions = stored_timings.compare(&timings, threshold);
+
+        if format != OutputFormat::Text {
+            return regressions;
+        }
+
+        println!();
+        if regressions.is_empty() {
+            println!("No regressions beyond {threshold}%.");
+        } else {
+            println!("{ANSI_BOLD}Regressions beyond {threshold}%:{ANSI_RESET}");
+            for regression in &regressions {
+                println!(
+                    "Day {}: {:.2}ms -> {:.2}ms (+{:.1}%)",
+                    regression.day,
+                    regression.previous_nanos / 1_000_000_f64,
+                    regression.current_nanos / 1_000_000_f64,
+                    regression.change_percent()
+                );
+            }
+        }
+
+        regressions
+    });
+
+    if store {
+        let merged_timings = stored_timings.merge(&timings);
+        merged_timings.store_file(year).unwrap();
+
+        // the readme only lists the benchmarks of the flat layout year.
+        let result = if year.is_flat() {
+            readme_benchmarks::update(merged_timings)
+        } else {
+            Ok(())
+        };
+
+        match result {
+            Ok(()) => {
+                if format == OutputFormat::Text {
+                    println!();
+                    println!("Stored updated benchmarks.");
+                }
+            }
+            Err(_) => {
+                eprintln!("Failed to store updated benchmarks.");
+            }
+        }
+    }
+
+    if regressions.is_some_and(|regressions| !regressions.is_empty()) {
+        process::exit(1);
+    }
+}
+
+/// The days to bench: the given day, or every day that is not fully benched yet unless `run_all`.
+/// Comparing runs every day as well, as only the fully benched days have a baseline.
+fn days_to_run(
+    stored_timings: &Timings,
+    day: Option<Day>,
+    run_all: bool,
+    compare: bool,
+) -> HashSet<Day> {
+    day.map_or_else(
         || {
-            if run_all {
+            if run_all || compare {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
@@ -19,22 +105,46 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         },
         |day| HashSet::from([day]),
-    );
-
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    )
+}
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::days_to_run;
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+    };
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
+    fn timing(total_nanos: f64) -> Timing {
+        Timing {
+            day: day!(1),
+            part_1_nanos: Some(total_nanos / 2.0),
+            part_2_nanos: Some(total_nanos / 2.0),
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_status: None,
+            part_2_status: None,
+            total_nanos,
         }
     }
+
+    #[test]
+    fn compares_fully_benched_days() {
+        let stored = Timings {
+            data: vec![timing(10_000_000_f64)],
+            history: vec![],
+        };
+
+        assert!(!days_to_run(&stored, None, false, false).contains(&day!(1)));
+        assert!(days_to_run(&stored, None, false, true).contains(&day!(1)));
+
+        let current = Timings {
+            data: vec![timing(20_000_000_f64)],
+            history: vec![],
+        };
+        let regressions = stored.compare(&current, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].day, day!(1));
+    }
 }
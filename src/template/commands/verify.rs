@@ -0,0 +1,57 @@
+use std::{collections::HashSet, process};
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::run_days;
+use crate::template::runner::RunOptions;
+use crate::template::{
+    all_days, find_solution, Day, Puzzle, Solution, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle(solutions: &[&dyn Solution], year: Year, day: Option<Day>) {
+    let answers = Answers::read_from_file(year);
+
+    let days_to_run = day.map_or_else(
+        || {
+            all_days()
+                .filter(|day| find_solution(solutions, Puzzle::new(year, *day)).is_some())
+                .collect()
+        },
+        |day| HashSet::from([day]),
+    );
+
+    let results = run_days(solutions, year, &days_to_run, &RunOptions::default());
+
+    println!();
+    println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+
+    let mut mismatches = 0;
+
+    for result in &results {
+        for part in &result.parts {
+            let label = match &result.input {
+                Some(input) => format!("Day {} Part {} ({input})", result.day, part.part),
+                None => format!("Day {} Part {}", result.day, part.part),
+            };
+
+            match (
+                answers.get(result.day, result.input.as_deref(), part.part),
+                &part.answer,
+            ) {
+                (None, _) => println!("{label}: no recorded answer"),
+                (Some(expected), Some(actual)) if expected == actual => println!("{label}: ✔"),
+                (Some(expected), actual) => {
+                    mismatches += 1;
+                    println!(
+                        "{label}: ✖ expected {expected}, got {}",
+                        actual.as_deref().unwrap_or("nothing")
+                    );
+                }
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        eprintln!("{mismatches} answer(s) do not match the recorded answers.");
+        process::exit(1);
+    }
+}
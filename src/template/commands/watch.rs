@@ -0,0 +1,255 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::runner::PartResult;
+use crate::template::{Puzzle, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Rerun the tests and the solution of a puzzle whenever its source, examples or input change.
+pub fn handle(puzzle: Puzzle, release: bool) {
+    let mut last_change = None;
+    let mut previous: Vec<PartResult> = vec![];
+
+    loop {
+        let change = latest_change(puzzle);
+
+        if change != last_change {
+            last_change = change;
+            print!("{ANSI_CLEAR}");
+            println!("Watching day {puzzle} for changes...\n");
+
+            run_tests(puzzle, release);
+            let results = run_solution(puzzle, release);
+            print_results(&results, &previous);
+
+            if !results.is_empty() {
+                previous = results;
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Files that trigger a rerun: `src/bin/NN.rs`, `data/examples/NN*.txt`, their answers
+/// sidecars and `data/inputs/NN.txt`.
+fn watched_paths(puzzle: Puzzle) -> Vec<PathBuf> {
+    let day = puzzle.day;
+    let mut paths = vec![puzzle.bin_path(), puzzle.data_file("inputs", ".txt")];
+
+    if let Ok(entries) = fs::read_dir(puzzle.year.data_dir().join("examples")) {
+        paths.extend(
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| {
+                            name.starts_with(&day.to_string())
+                                && (name.ends_with(".txt") || name.ends_with(".answers"))
+                        })
+                }),
+        );
+    }
+
+    paths
+}
+
+/// Returns the most recent modification time of the watched files.
+fn latest_change(puzzle: Puzzle) -> Option<SystemTime> {
+    latest_modification(&watched_paths(puzzle))
+}
+
+/// Returns the most recent modification time of the files that exist.
+fn latest_modification(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .max()
+}
+
+fn cargo_args(command: &str, puzzle: Puzzle, release: bool) -> Vec<String> {
+    let mut args = vec![
+        command.to_string(),
+        "--quiet".to_string(),
+        "--bin".to_string(),
+        puzzle.bin_name(),
+    ];
+
+    if release {
+        args.push("--release".to_string());
+    }
+
+    args
+}
+
+fn run_tests(puzzle: Puzzle, release: bool) {
+    println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+
+    let output = Command::new("cargo")
+        .args(cargo_args("test", puzzle, release))
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => println!("✔ passed\n"),
+        Ok(output) => {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            println!("✖ failed\n");
+        }
+        Err(e) => eprintln!("failed to run tests: {e}\n"),
+    }
+}
+
+fn run_solution(puzzle: Puzzle, release: bool) -> Vec<PartResult> {
+    println!("{ANSI_BOLD}Solution{ANSI_RESET}");
+
+    let mut args = cargo_args("run", puzzle, release);
+    args.extend(["--".to_string(), "--format".to_string(), "json".to_string()]);
+
+    let output = match Command::new("cargo")
+        .args(args)
+        .stderr(Stdio::inherit())
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("failed to run solution: {e}");
+            return vec![];
+        }
+    };
+
+    if !output.status.success() {
+        println!("✖ failed\n");
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.parse::<JsonValue>().ok())
+        .filter_map(|json| PartResult::try_from(&json).ok())
+        .collect()
+}
+
+fn print_results(results: &[PartResult], previous: &[PartResult]) {
+    for result in results {
+        let answer = result.answer.as_deref().unwrap_or("✖");
+        let before = previous.iter().find(|p| p.part == result.part);
+
+        println!(
+            "Part {}: {ANSI_BOLD}{answer}{ANSI_RESET} {ANSI_ITALIC}({:.1?}{}){ANSI_RESET}",
+            result.part,
+            result.duration,
+            format_delta(result, before)
+        );
+    }
+}
+
+/// Formats the change in duration against the previous run, and notes a changed answer.
+fn format_delta(result: &PartResult, previous: Option<&PartResult>) -> String {
+    let Some(previous) = previous else {
+        return String::new();
+    };
+
+    let current = result.duration.as_nanos() as f64;
+    let before = previous.duration.as_nanos() as f64;
+    let sign = if current >= before { "+" } else { "-" };
+    let delta = Duration::from_nanos((current - before).abs() as u64);
+
+    let mut s = format!(", {sign}{delta:.1?}");
+
+    if before > 0.0 {
+        s.push_str(&format!(
+            " / {sign}{:.1}%",
+            ((current - before) / before * 100.0).abs()
+        ));
+    }
+
+    if previous.answer != result.answer {
+        s.push_str(", answer changed");
+    }
+
+    s
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        env, fs,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{format_delta, latest_modification};
+    use crate::{
+        day,
+        template::runner::{PartResult, PartStatus},
+    };
+
+    fn result(millis: u64, answer: &str) -> PartResult {
+        PartResult {
+            day: day!(1),
+            part: 1,
+            status: PartStatus::Solved,
+            answer: Some(answer.into()),
+            duration: Duration::from_millis(millis),
+            samples: 1,
+            stats: None,
+            input: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn detects_changed_files() {
+        let dir = env::temp_dir().join("aoc-watch-changes");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let (source, example) = (dir.join("01.rs"), dir.join("01.txt"));
+        let paths = [source.clone(), example.clone()];
+        assert_eq!(latest_modification(&paths), None);
+
+        let earlier = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let later = earlier + Duration::from_secs(1);
+
+        fs::write(&source, "").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&source)
+            .unwrap()
+            .set_modified(earlier)
+            .unwrap();
+        assert_eq!(latest_modification(&paths), Some(earlier));
+
+        fs::write(&example, "").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&example)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(latest_modification(&paths), Some(later));
+    }
+
+    #[test]
+    fn formats_deltas() {
+        assert_eq!(format_delta(&result(10, "1"), None), "");
+        assert_eq!(
+            format_delta(&result(15, "1"), Some(&result(10, "1"))),
+            ", +5.0ms / +50.0%"
+        );
+        assert_eq!(
+            format_delta(&result(5, "2"), Some(&result(10, "1"))),
+            ", -5.0ms / -50.0%, answer changed"
+        );
+    }
+}
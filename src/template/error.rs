@@ -0,0 +1,65 @@
+use std::{fmt::Display, io, path::PathBuf};
+
+use crate::template::Puzzle;
+
+/// Errors of the template helpers, with the context they occurred in.
+#[derive(Debug)]
+pub enum Error {
+    /// A data file of a puzzle, e.g. an input or example, could not be read.
+    ReadFile {
+        path: PathBuf,
+        puzzle: Puzzle,
+        /// The part of a part-specific file like `01-2.txt`.
+        part: Option<u8>,
+        source: io::Error,
+    },
+    /// A file passed with `--input` could not be read.
+    ReadInput { path: PathBuf, source: io::Error },
+    /// The input could not be read from stdin.
+    ReadStdin(io::Error),
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ReadFile { source, .. } | Error::ReadInput { source, .. } => Some(source),
+            Error::ReadStdin(source) => Some(source),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ReadFile {
+                path,
+                puzzle,
+                part,
+                source,
+            } => {
+                write!(f, "could not read {} for day {puzzle}", path.display())?;
+                if let Some(part) = part {
+                    write!(f, ", part {part}")?;
+                }
+                write!(f, ": {source}")
+            }
+            Error::ReadInput { path, source } => {
+                write!(f, "could not read input {}: {source}", path.display())
+            }
+            Error::ReadStdin(source) => write!(f, "could not read input from stdin: {source}"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{day, template::try_read_file_part};
+
+    #[test]
+    fn reports_missing_files() {
+        let error = try_read_file_part("missing", day!(7), 2).unwrap_err();
+        let message = error.to_string();
+
+        assert!(message.starts_with("could not read data/missing/07-2.txt for day 07, part 2: "));
+    }
+}
@@ -0,0 +1,222 @@
+/// A code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The part whose description contains the block.
+    pub part: u8,
+    pub text: String,
+}
+
+/// Example blocks and expected answers found in the markdown of a puzzle description.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub blocks: Vec<CodeBlock>,
+    /// The expected example answer of each part, if its description is known.
+    pub answers: [Option<String>; 2],
+}
+
+impl PuzzleExamples {
+    /// Extracts the code blocks and expected answers from a puzzle description.
+    /// Answers are emphasized code (e.g. *`514579`*), and the last one in the description of a
+    /// part is usually the answer to its example.
+    pub fn extract(markdown: &str) -> Self {
+        let mut examples = PuzzleExamples::default();
+        let mut part = 1;
+        let mut block: Option<String> = None;
+
+        for line in markdown.lines() {
+            if line.starts_with("```") {
+                match block.take() {
+                    Some(text) => examples.blocks.push(CodeBlock { part, text }),
+                    None => block = Some(String::new()),
+                }
+                continue;
+            }
+
+            if let Some(text) = &mut block {
+                text.push_str(line);
+                text.push('\n');
+                continue;
+            }
+
+            if line.starts_with("## --- Part Two") {
+                part = 2;
+            }
+
+            if let Some(answer) = last_emphasized_code(line) {
+                examples.answers[usize::from(part) - 1] = Some(answer.to_string());
+            }
+        }
+
+        examples
+    }
+}
+
+fn last_emphasized_code(line: &str) -> Option<&str> {
+    let start = line.rfind("*`")? + 2;
+    let len = line[start..].find("`*")?;
+    Some(&line[start..start + len])
+}
+
+/// Replaces the `None` or `Ok(None)` assertion in the test of `part` with the expected answer,
+/// written for the answer type of the part. Returns [`None`] if the test does not assert `None`
+/// anymore.
+pub fn fill_in_answer(module: &str, part: u8, answer: &str) -> Option<String> {
+    let (test, func) = match part {
+        1 => ("fn test_part_one()", "fn part_one("),
+        2 => ("fn test_part_two()", "fn part_two("),
+        _ => return None,
+    };
+
+    let test_start = module.find(test)?;
+    let test_end = module[test_start + test.len()..]
+        .find("fn ")
+        .map_or(module.len(), |i| test_start + test.len() + i);
+
+    let (start, assertion, wrap) = [
+        ("assert_eq!(result, None);", "Some"),
+        ("assert_eq!(result, Ok(None));", "Ok(Some"),
+    ]
+    .into_iter()
+    .find_map(|(assertion, wrap)| {
+        let start = test_start + module[test_start..test_end].find(assertion)?;
+        Some((start, assertion, wrap))
+    })?;
+
+    // answers are strings for `String` parts. If the signature is not found, guess from the answer.
+    let is_string = match answer_type(module, func) {
+        Some(answer_type) => answer_type == "String",
+        None => answer.parse::<i128>().is_err(),
+    };
+
+    let expected = if is_string {
+        format!("{answer:?}.to_string()")
+    } else {
+        answer.to_string()
+    };
+    let close = ")".repeat(wrap.matches('(').count());
+
+    Some(format!(
+        "{}assert_eq!(result, {wrap}({expected}){close});{}",
+        &module[..start],
+        &module[start + assertion.len()..]
+    ))
+}
+
+/// The `T` of the `Option<T>` returned by a part, e.g. `u64` for `fn part_one(..) -> Option<u64>`.
+fn answer_type<'a>(module: &'a str, func: &str) -> Option<&'a str> {
+    let signature = &module[module.find(func)?..];
+    let signature = &signature[signature.find("->")?..signature.find('{')?];
+    let start = signature.find("Option<")? + "Option<".len();
+
+    let mut depth = 0;
+    let len = signature[start..].find(|c| {
+        match c {
+            '<' => depth += 1,
+            '>' if depth == 0 => return true,
+            '>' => depth -= 1,
+            _ => {}
+        }
+        false
+    })?;
+
+    Some(signature[start..start + len].trim())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fill_in_answer, CodeBlock, PuzzleExamples};
+
+    #[test]
+    fn extracts_examples() {
+        let markdown = [
+            "## --- Day 1: Test ---",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1721",
+            "979",
+            "```",
+            "",
+            "Multiplying them together produces `1721 * 299 = `*`514579`*.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "In the same example, the product is *`241861950`*.",
+            "",
+        ]
+        .join("\n");
+
+        let examples = PuzzleExamples::extract(&markdown);
+        assert_eq!(
+            examples.blocks,
+            [CodeBlock {
+                part: 1,
+                text: "1721\n979\n".into()
+            }]
+        );
+        assert_eq!(
+            examples.answers,
+            [Some("514579".into()), Some("241861950".into())]
+        );
+    }
+
+    #[test]
+    fn extracts_examples_without_part_two() {
+        let examples = PuzzleExamples::extract("There are *`7`* trees.\n");
+        assert!(examples.blocks.is_empty());
+        assert_eq!(examples.answers, [Some("7".into()), None]);
+    }
+
+    #[test]
+    fn fills_in_answers() {
+        let module = [
+            "fn test_part_one() {",
+            "    assert_eq!(result, None);",
+            "}",
+            "fn test_part_two() {",
+            "    assert_eq!(result, None);",
+            "}",
+        ]
+        .join("\n");
+
+        let module = fill_in_answer(&module, 2, "abc").unwrap();
+        let module = fill_in_answer(&module, 1, "42").unwrap();
+        assert_eq!(
+            module,
+            [
+                "fn test_part_one() {",
+                "    assert_eq!(result, Some(42));",
+                "}",
+                "fn test_part_two() {",
+                "    assert_eq!(result, Some(\"abc\".to_string()));",
+                "}",
+            ]
+            .join("\n")
+        );
+
+        assert!(fill_in_answer(&module, 1, "43").is_none());
+    }
+
+    #[test]
+    fn fills_in_answers_of_the_answer_type() {
+        let module = [
+            "pub fn part_one(input: &str) -> Result<Option<String>, ParseError<Infallible>> {",
+            "}",
+            "pub fn part_two(input: &str) -> Option<u64> {",
+            "}",
+            "fn test_part_one() {",
+            "    assert_eq!(result, Ok(None));",
+            "}",
+            "fn test_part_two() {",
+            "    assert_eq!(result, None);",
+            "}",
+        ]
+        .join("\n");
+
+        let module = fill_in_answer(&module, 1, "42").unwrap();
+        let module = fill_in_answer(&module, 2, "7").unwrap();
+        assert!(module.contains("assert_eq!(result, Ok(Some(\"42\".to_string())));"));
+        assert!(module.contains("assert_eq!(result, Some(7));"));
+    }
+}
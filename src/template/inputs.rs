@@ -0,0 +1,97 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, Puzzle};
+
+/// An input file of a day.
+/// The default input lives at `data/inputs/NN.txt`, named variants at `data/inputs/NN/<name>.txt`.
+/// Inputs of other years than the default year live below `data/YYYY/inputs`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    /// Name of the variant, `None` for the default input.
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+/// Lists the inputs of a puzzle: the default input first, then the variants in alphabetical order.
+pub fn inputs_for(puzzle: Puzzle) -> Vec<Input> {
+    inputs_in(&puzzle.year.data_dir().join("inputs"), puzzle.day)
+}
+
+/// Lists the inputs of a day in `dir`. Without variants, the default input is listed even
+/// if it does not exist, so that reading it reports the missing file.
+fn inputs_in(dir: &Path, day: Day) -> Vec<Input> {
+    let default = Input {
+        name: None,
+        path: dir.join(format!("{day}.txt")),
+    };
+
+    let mut variants: Vec<Input> = fs::read_dir(dir.join(day.to_string()))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            Some(Input {
+                name: Some(name),
+                path,
+            })
+        })
+        .collect();
+
+    variants.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+    if variants.is_empty() || default.path.is_file() {
+        variants.insert(0, default);
+    }
+
+    variants
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::inputs_in;
+    use crate::day;
+
+    #[test]
+    fn lists_default_input() {
+        let dir = env::temp_dir().join("aoc-inputs-default");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let inputs = inputs_in(&dir, day!(1));
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name, None);
+        assert_eq!(inputs[0].path, dir.join("01.txt"));
+    }
+
+    #[test]
+    fn lists_input_variants() {
+        let dir = env::temp_dir().join("aoc-inputs-variants");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("07")).unwrap();
+        fs::write(dir.join("07.txt"), "").unwrap();
+        fs::write(dir.join("07").join("bob.txt"), "").unwrap();
+        fs::write(dir.join("07").join("alice.txt"), "").unwrap();
+        fs::write(dir.join("07").join("notes.md"), "").unwrap();
+
+        let names: Vec<Option<String>> = inputs_in(&dir, day!(7))
+            .into_iter()
+            .map(|input| input.name)
+            .collect();
+        assert_eq!(names, [None, Some("alice".into()), Some("bob".into())]);
+
+        fs::remove_file(dir.join("07.txt")).unwrap();
+        let names: Vec<Option<String>> = inputs_in(&dir, day!(7))
+            .into_iter()
+            .map(|input| input.name)
+            .collect();
+        assert_eq!(names, [Some("alice".into()), Some("bob".into())]);
+    }
+}
@@ -0,0 +1,4 @@
+// `build.rs` includes this file to find the examples of the solutions, keep it free of imports.
+
+/// The year whose files keep the flat layout, see `Year::FLAT`.
+pub const FLAT_YEAR: u16 = 2020;
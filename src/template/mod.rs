@@ -1,68 +1,213 @@
-use std::{env, fs};
+use std::{
+    env, fs,
+    io::{self, Read},
+    process,
+};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
 pub mod runner;
 
 pub use day::*;
+pub use error::Error;
+pub use solution::*;
+pub use year::*;
 
+mod answers;
 mod day;
+mod error;
+mod examples;
+mod inputs;
+mod layout;
 mod readme_benchmarks;
 mod run_multi;
+mod solution;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// Accepts a [`Day`] of the default year or a [`Puzzle`] of any year.
+///
+/// # Panics
+/// Panics with the path of the file if it cannot be read, see [`try_read_file`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: impl Into<Puzzle>) -> String {
+    try_read_file(folder, puzzle).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Helper function that reads a text file to a string, returning an [`Error`] on failure.
+pub fn try_read_file(folder: &str, puzzle: impl Into<Puzzle>) -> Result<String, Error> {
+    read_data_file(folder, puzzle.into(), None)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Panics
+/// Panics with the path of the file if it cannot be read, see [`try_read_file_part`].
+#[must_use]
+pub fn read_file_part(folder: &str, puzzle: impl Into<Puzzle>, part: u8) -> String {
+    try_read_file_part(folder, puzzle, part).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Helper function that reads a text file with a part suffix, returning an [`Error`] on failure.
+pub fn try_read_file_part(
+    folder: &str,
+    puzzle: impl Into<Puzzle>,
+    part: u8,
+) -> Result<String, Error> {
+    read_data_file(folder, puzzle.into(), Some(part))
+}
+
+fn read_data_file(folder: &str, puzzle: Puzzle, part: Option<u8>) -> Result<String, Error> {
+    let suffix = part.map_or_else(|| ".txt".to_string(), |part| format!("-{part}.txt"));
+    let path = puzzle.data_file(folder, &suffix);
+
+    fs::read_to_string(&path).map_err(|source| Error::ReadFile {
+        path,
+        puzzle,
+        part,
+        source,
+    })
+}
+
+/// Helper function that reads the input of a puzzle.
+/// Reads the file passed with `--input <path>`, or stdin for `--input -`, and falls back to
+/// `data/inputs/<day>.txt`. Exits with a message if the input cannot be read.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_input(puzzle: Puzzle) -> String {
+    try_read_input(puzzle).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    })
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the input of a puzzle, returning an [`Error`] on failure.
+pub fn try_read_input(puzzle: Puzzle) -> Result<String, Error> {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(index) = args.iter().position(|x| x == "--input") else {
+        return try_read_file("inputs", puzzle);
+    };
+
+    match args.get(index + 1).map(String::as_str) {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(Error::ReadStdin)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path).map_err(|source| Error::ReadInput {
+            path: path.into(),
+            source,
+        }),
+        None => {
+            eprintln!("Unexpected command-line input. Format: --input <path|->");
+            process::exit(1);
+        }
+    }
+}
+
+/// Generates a test for every example of the current solution that has an answers sidecar.
+/// E.g. `data/examples/07-2.txt` is tested against the parts listed in `data/examples/07-2.answers`:
+///
+/// ```text
+/// 1: 4
+/// 2: 126
+/// ```
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The tests are generated by `build.rs`, so adding an example does not require any code changes.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/examples/",
+            env!("CARGO_BIN_NAME"),
+            ".rs"
+        ));
+    };
+}
+
+/// Creates the constant `DAY`, the function `puzzle()` and sets up the input and runner for each part.
+/// Also creates the `DaySolution` type, which the main binary uses to run the day in-process.
+///
+/// The optional `year = YYYY` parameter marks a solution of a year other than the default year.
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl [] $day, [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl [] $day, [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl [] $day, [part_two, 2]);
+    };
+    ($day:expr, year = $year:expr) => {
+        $crate::solution!(@impl [$year] $day, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, year = $year:expr, 1) => {
+        $crate::solution!(@impl [$year] $day, [part_one, 1]);
+    };
+    ($day:expr, year = $year:expr, 2) => {
+        $crate::solution!(@impl [$year] $day, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl [$($year:expr)?] $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current puzzle, of the given year or of the flat layout.
+        fn puzzle() -> $crate::template::Puzzle {
+            $crate::template::Puzzle::resolve(None $(.or(Some($crate::year!($year))))?, DAY)
+        }
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input: std::sync::Arc<str> = $crate::template::read_input(puzzle()).into();
+            $( run_part(|input: std::sync::Arc<str>| $func(&input), input.clone(), puzzle(), $part); )*
+        }
+
+        /// Entry point for running this day from the main binary.
+        pub struct DaySolution;
+
+        impl $crate::template::Solution for DaySolution {
+            fn year(&self) -> $crate::template::Year {
+                puzzle().year
+            }
+
+            fn day(&self) -> $crate::template::Day {
+                DAY
+            }
+
+            fn run(
+                &self,
+                input: &str,
+                options: &$crate::template::runner::RunOptions,
+            ) -> Vec<$crate::template::runner::PartResult> {
+                use $crate::template::runner::*;
+                let input: std::sync::Arc<str> = input.into();
+                vec![$(
+                    run_part_with(
+                        |input: std::sync::Arc<str>| $func(&input),
+                        input.clone(),
+                        puzzle(),
+                        $part,
+                        options,
+                    ),
+                )*]
+            }
         }
     };
 }
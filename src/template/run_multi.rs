@@ -1,257 +1,382 @@
-use std::{collections::HashSet, io};
+use std::{
+    cmp,
+    collections::{BTreeMap, HashSet},
+    fs, io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Puzzle, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
-    all_days,
+    all_days, find_solution,
+    inputs::{inputs_for, Input},
+    runner::{print_part_result, OutputFormat, PartResult, PartStatus, RunOptions},
     timings::{Timing, Timings},
+    Solution,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Results of all parts of a day that was run on one input.
+#[derive(Clone, Debug)]
+pub struct DayResult {
+    pub day: Day,
+    /// Name of the input variant, `None` for the default input.
+    pub input: Option<String>,
+    pub parts: Vec<PartResult>,
+}
 
-    let mut need_space = false;
+pub fn run_multi(
+    solutions: &[&dyn Solution],
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &RunOptions,
+) -> Option<Timings> {
+    let results = run_days(solutions, year, days_to_run, options);
+
+    if options.is_timed {
+        let timings = Timings {
+            // only the default input is benchmarked over time, variants are only printed.
+            data: results
+                .iter()
+                .filter(|result| result.input.is_none())
+                .map(|result| collect_timing(result.day, &result.parts))
+                .collect(),
+            history: vec![],
+        };
+        let total_millis = timings.total_millis();
+        if options.format == OutputFormat::Text {
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
+        Some(timings)
+    } else {
+        None
+    }
+}
 
+/// Run the solutions of the given days of `year` in order, skipping days without a solution or input.
+/// With more than one job, days run concurrently but their output is still printed grouped
+/// and in order. Timed runs are always serial so that benchmarks do not compete for cores.
+pub fn run_days(
+    solutions: &[&dyn Solution],
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &RunOptions,
+) -> Vec<DayResult> {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut results: Vec<DayResult> = Vec::with_capacity(days.len());
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    if options.jobs <= 1 || options.is_timed {
+        for (index, &day) in days.iter().enumerate() {
+            print_header(day, index, options);
+            let run = run_day(solutions, Puzzle::new(year, day), options);
+            report(day, run, options, &mut results);
+        }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        return results;
+    }
+
+    let quiet_options = RunOptions {
+        quiet: true,
+        ..*options
+    };
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..cmp::min(options.jobs, days.len()) {
+            let sender = sender.clone();
+            let (days, next, quiet_options) = (&days, &next, &quiet_options);
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                let run = run_day(solutions, Puzzle::new(year, day), quiet_options);
+                if sender.send((index, run)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        // print finished days as soon as all days before them have been printed.
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (index, run) in receiver {
+            pending.insert(index, run);
+
+            while let Some(run) = pending.remove(&next_to_print) {
+                let day = days[next_to_print];
+                print_header(day, next_to_print, options);
+                report(day, run, &quiet_options, &mut results);
+                next_to_print += 1;
             }
-        });
+        }
+    });
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
-    } else {
-        None
-    }
+    results
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
+/// Outcome of running a single day.
+enum DayRun {
+    NotSolved,
+    /// Results for every input of the day, or the error that occurred reading it.
+    Inputs(Vec<(Input, io::Result<Vec<PartResult>>)>),
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
+/// Days with input variants run quietly, their results are printed as one table.
+fn has_variants(inputs: &[Input]) -> bool {
+    inputs.iter().any(|input| input.name.is_some())
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+fn run_day(solutions: &[&dyn Solution], puzzle: Puzzle, options: &RunOptions) -> DayRun {
+    let Some(solution) = find_solution(solutions, puzzle) else {
+        return DayRun::NotSolved;
+    };
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+    let inputs = inputs_for(puzzle);
+
+    let options = RunOptions {
+        quiet: options.quiet || has_variants(&inputs),
+        ..*options
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    DayRun::Inputs(
+        inputs
+            .into_iter()
+            .map(|input| {
+                let parts = fs::read_to_string(&input.path).map(|text| {
+                    let mut parts = solution.run(&text, &options);
+                    for part in &mut parts {
+                        part.input.clone_from(&input.name);
+                    }
+                    parts
+                });
+                (input, parts)
+            })
+            .collect(),
+    )
+}
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+fn print_header(day: Day, index: usize, options: &RunOptions) {
+    if options.format != OutputFormat::Text {
+        return;
+    }
 
-        if is_release {
-            args.push("--release");
-        }
+    if index > 0 {
+        println!();
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+/// Print the outcome of a day, including its parts if they were run quietly.
+fn report(day: Day, run: DayRun, options: &RunOptions, results: &mut Vec<DayResult>) {
+    let inputs = match run {
+        DayRun::NotSolved => {
+            if options.format == OutputFormat::Text {
+                println!("Not solved.");
+            }
+            return;
+        }
+        DayRun::Inputs(inputs) => inputs,
+    };
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    let is_table = inputs.iter().any(|(input, _)| input.name.is_some());
+    let mut day_results = vec![];
+
+    for (input, parts) in inputs {
+        match parts {
+            Ok(parts) => day_results.push(DayResult {
+                day,
+                input: input.name,
+                parts,
+            }),
+            Err(e) => eprintln!("Could not read input file {}: {e}", input.path.display()),
+        }
+    }
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    if is_table && options.format == OutputFormat::Text {
+        print_inputs_table(&day_results);
+    } else if is_table || options.quiet {
+        for part in day_results.iter().flat_map(|result| &result.parts) {
+            print_part_result(part, options.format);
+        }
+    }
 
-        let mut output = vec![];
+    results.extend(day_results);
+}
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+/// Print the answers and durations of a day's parts with one row per input.
+pub fn print_inputs_table(results: &[DayResult]) {
+    let parts = results
+        .iter()
+        .map(|result| result.parts.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut rows: Vec<Vec<String>> = vec![std::iter::once("Input".to_string())
+        .chain((1..=parts).map(|part| format!("Part {part}")))
+        .collect()];
+
+    for result in results {
+        rows.push(
+            std::iter::once(result.input.as_deref().unwrap_or("default").to_string())
+                .chain(result.parts.iter().map(format_cell))
+                .collect(),
+        );
+    }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    let widths: Vec<usize> = (0..=parts)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for (index, row) in rows.iter().enumerate() {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        if index == 0 {
+            println!("{ANSI_BOLD}{}{ANSI_RESET}", line.trim_end());
+        } else {
+            println!("{}", line.trim_end());
         }
+    }
+}
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+fn format_cell(part: &PartResult) -> String {
+    match (&part.answer, part.status) {
+        (Some(answer), _) if answer.contains('\n') => format!("▼ ({:.1?})", part.duration),
+        (Some(answer), _) => format!("{answer} ({:.1?})", part.duration),
+        (None, status) if status.is_failure() => format!("✖ {status}"),
+        (None, _) => "✖".into(),
     }
+}
+
+/// Build the benchmark timing of a day from the results of its parts.
+/// The status of every part is kept, but only solved parts contribute durations.
+fn collect_timing(day: Day, results: &[PartResult]) -> Timing {
+    let mut timing = Timing {
+        day,
+        part_1_nanos: None,
+        part_2_nanos: None,
+        part_1_stats: None,
+        part_2_stats: None,
+        part_1_status: None,
+        part_2_status: None,
+        total_nanos: 0_f64,
+    };
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    for result in results {
+        let (nanos, stats, status) = match result.part {
+            1 => (
+                &mut timing.part_1_nanos,
+                &mut timing.part_1_stats,
+                &mut timing.part_1_status,
+            ),
+            2 => (
+                &mut timing.part_2_nanos,
+                &mut timing.part_2_stats,
+                &mut timing.part_2_status,
+            ),
+            _ => continue,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        *status = Some(result.status);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if result.status == PartStatus::Solved {
+            let duration = result.duration.as_nanos() as f64;
+            (*nanos, *stats) = (Some(duration), result.stats);
+            timing.total_nanos += duration;
+        }
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    timing
+}
 
-                timings.total_nanos += nanos;
-            });
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
 
-        timings
-    }
+    use super::collect_timing;
+    use crate::{
+        day,
+        template::runner::{PartResult, PartStatus},
+    };
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    fn part_result(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+        PartResult {
+            day: day!(1),
+            part,
+            answer: answer.map(str::to_string),
+            duration: Duration::from_nanos(nanos),
+            samples: 10,
+            stats: None,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            input: None,
+            error: None,
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn collects_timings_of_parts() {
+        let res = collect_timing(
+            day!(1),
+            &[
+                part_result(1, Some("0"), 74),
+                part_result(2, Some("10"), 74_130_000),
+            ],
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1_nanos.unwrap(), 74_f64);
+        assert_eq!(res.part_2_nanos.unwrap(), 74_130_000_f64);
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn collects_missing_parts() {
+        let res = collect_timing(
+            day!(1),
+            &[part_result(1, None, 100), part_result(2, None, 100)],
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1_nanos.is_none(), true);
+        assert_eq!(res.part_2_nanos.is_none(), true);
+        assert_eq!(res.part_1_status, Some(PartStatus::Unsolved));
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
-
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn collects_failed_parts() {
+        let mut failed = part_result(2, None, 100);
+        failed.status = PartStatus::Panicked;
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+        let res = collect_timing(day!(1), &[part_result(1, Some("0"), 74), failed]);
+        assert_eq!(res.total_nanos, 74_f64);
+        assert_eq!(res.part_1_status, Some(PartStatus::Solved));
+        assert_eq!(res.part_2_status, Some(PartStatus::Panicked));
+        assert_eq!(res.part_2_nanos.is_none(), true);
     }
 }
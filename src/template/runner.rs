@@ -1,35 +1,507 @@
 /// Encapsulates code that interacts with solution functions.
+use std::collections::HashMap;
+use std::error::Error;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, AocClientError, SubmissionOutcome};
+use crate::template::submissions::Submissions;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Puzzle, ANSI_ITALIC, ANSI_RESET};
+
+/// Stack size of the threads parts run on, matching the default of the main thread on linux.
+const PART_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// How the results of a solution run are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output.
+    #[default]
+    Text,
+    /// One JSON record per part and line.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => f.write_str("text"),
+            OutputFormat::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of `text` or `json`")
+    }
+}
+
+/// Controls how long and how often a solution part is run when benching.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    /// Approximate time spent measuring a part. A tenth of it is additionally spent warming up.
+    pub budget: Duration,
+    /// Fixed number of samples to take, overriding the budget.
+    pub samples: Option<u128>,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            budget: Duration::from_secs(1),
+            samples: None,
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+/// Parses the `--samples` option, which needs at least one sample to compute statistics.
+pub fn parse_samples(value: &str) -> Result<u128, SamplesFromStrError> {
+    match value.parse() {
+        Ok(samples) if samples > 0 => Ok(samples),
+        _ => Err(SamplesFromStrError),
+    }
+}
+
+/// An error which can be returned when parsing the number of samples to take.
+#[derive(Debug)]
+pub struct SamplesFromStrError;
+
+impl Error for SamplesFromStrError {}
+
+impl Display for SamplesFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a number of samples greater than 0")
+    }
+}
+
+/// Statistics collected while benching a solution part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Number of samples outside of the Tukey fences, i.e. further than 1.5 IQR from the quartiles.
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Compute statistics for a non-empty set of samples. Sorts the samples in place.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        let len = nanos.len() as f64;
+
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len;
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        BenchStats {
+            samples: samples.len() as u128,
+            mean: duration_from_nanos(mean),
+            median: duration_from_nanos(percentile(&nanos, 0.5)),
+            min: samples[0],
+            p95: duration_from_nanos(percentile(&nanos, 0.95)),
+            std_dev: duration_from_nanos(variance.sqrt()),
+            outliers: nanos.iter().filter(|&&x| x < low || x > high).count(),
+        }
+    }
+}
+
+fn duration_from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Linearly interpolated percentile of sorted values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Options that control how solution parts are run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    pub format: OutputFormat,
+    pub bench: BenchConfig,
+    /// Record the answers as accepted answers in `data/answers.json`.
+    pub record: bool,
+    /// Do not print results while running. The caller prints them with [`print_part_result`].
+    pub quiet: bool,
+    /// Number of days `run_multi` runs concurrently. Timed runs are always serial.
+    pub jobs: usize,
+    /// Stop waiting for a part that has not produced a result after this long.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Read the options from the arguments passed to a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let format = args
+            .iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .map_or(Ok(OutputFormat::Text), |x| x.parse());
+
+        let Ok(format) = format else {
+            eprintln!("Unexpected command-line input. Format: --format <text|json>");
+            process::exit(1);
+        };
+
+        let mut bench = BenchConfig::default();
+
+        if let Some(i) = args.iter().position(|x| x == "--budget") {
+            let Some(millis) = args.get(i + 1).and_then(|x| x.parse().ok()) else {
+                eprintln!("Unexpected command-line input. Format: --budget <millis>");
+                process::exit(1);
+            };
+            bench.budget = Duration::from_millis(millis);
+        }
+
+        if let Some(i) = args.iter().position(|x| x == "--samples") {
+            let Some(samples) = args.get(i + 1).and_then(|x| parse_samples(x).ok()) else {
+                eprintln!("Unexpected command-line input. Format: --samples <count>, count > 0");
+                process::exit(1);
+            };
+            bench.samples = Some(samples);
+        }
+
+        let mut timeout = None;
+
+        if let Some(i) = args.iter().position(|x| x == "--timeout") {
+            let Some(millis) = args.get(i + 1).and_then(|x| x.parse().ok()) else {
+                eprintln!("Unexpected command-line input. Format: --timeout <millis>");
+                process::exit(1);
+            };
+            timeout = Some(Duration::from_millis(millis));
+        }
+
+        let is_timed = args.iter().any(|x| x == "--time");
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+        // a timed out part cannot be stopped and would skew the benchmarks.
+        if is_timed && timeout.is_some() {
+            eprintln!(
+                "Unexpected command-line input. `--timeout` cannot be combined with `--time`"
+            );
+            process::exit(1);
+        }
+
+        RunOptions {
+            is_timed,
+            format,
+            bench,
+            record: args.iter().any(|x| x == "--record"),
+            timeout,
+            ..RunOptions::default()
+        }
+    }
+}
+
+/// Status of a solution part after running it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part produced an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part panicked.
+    Panicked,
+    /// The part did not finish within the timeout.
+    TimedOut,
+    /// The part returned an error.
+    Failed,
+}
+
+impl PartStatus {
+    /// Whether the part did not run to completion.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            PartStatus::Panicked | PartStatus::TimedOut | PartStatus::Failed
+        )
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Solved => f.write_str("solved"),
+            PartStatus::Unsolved => f.write_str("unsolved"),
+            PartStatus::Panicked => f.write_str("panicked"),
+            PartStatus::TimedOut => f.write_str("timed out"),
+            PartStatus::Failed => f.write_str("failed"),
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "panicked" => Ok(PartStatus::Panicked),
+            "timed out" => Ok(PartStatus::TimedOut),
+            "failed" => Ok(PartStatus::Failed),
+            _ => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// Outcome of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    pub status: PartStatus,
+    /// Name of the input variant the part ran on, `None` for the default input.
+    pub input: Option<String>,
+    /// The error message of a part that failed.
+    pub error: Option<String>,
+}
+
+/// The return value of a solution part. Parts return an `Option` of their answer, or - if they
+/// can fail - a `Result` of it, whose error is reported instead of panicking.
+pub trait PartOutput {
+    type Answer: Display;
+
+    /// Converts the output into the answer, or the message of the error the part failed with.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<Option<T>, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// Run a solution part with the options passed on the command-line.
+pub fn run_part<I: Clone + Send + 'static, R: PartOutput + 'static>(
+    func: impl Fn(I) -> R + Send + 'static,
+    input: I,
+    puzzle: impl Into<Puzzle>,
+    part: u8,
+) -> PartResult
+where
+    R::Answer: Send + 'static,
+{
+    run_part_with(func, input, puzzle, part, &RunOptions::from_args())
+}
+
+/// Run a solution part with the provided options.
+pub fn run_part_with<I: Clone + Send + 'static, R: PartOutput + 'static>(
+    func: impl Fn(I) -> R + Send + 'static,
+    input: I,
+    puzzle: impl Into<Puzzle>,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult
+where
+    R::Answer: Send + 'static,
+{
+    let puzzle = puzzle.into();
+    let func = move |input: I| func(input).into_answer();
     let part_str = format!("Part {part}");
+    let is_text = options.format == OutputFormat::Text && !options.quiet;
+
+    let hook = {
+        let part_str = part_str.clone();
+        move |result: &Result<Option<R::Answer>, String>| {
+            if let (true, Ok(result)) = (is_text, result) {
+                print_result(result, &part_str, "");
+            }
+        }
+    };
+
+    let timer = Instant::now();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, stats, status, error) =
+        match run_isolated(func, input, puzzle, part, options, hook) {
+            Ok((Ok(result), duration, stats)) => {
+                let status = if result.is_some() {
+                    PartStatus::Solved
+                } else {
+                    PartStatus::Unsolved
+                };
+                (result, duration, stats, status, None)
+            }
+            Ok((Err(error), duration, _)) => {
+                (None, duration, None, PartStatus::Failed, Some(error))
+            }
+            Err(status) => (None, timer.elapsed(), None, status, None),
+        };
+
+    if is_text {
+        if status.is_failure() {
+            print_failure(
+                &part_str,
+                status,
+                error.as_deref(),
+                &format_duration(&duration, None),
+            );
+        } else {
+            print_result(
+                &result,
+                &part_str,
+                &format_duration(&duration, stats.as_ref()),
+            );
+        }
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let answer = result.map(|result| result.to_string());
+
+    let part_result = PartResult {
+        day: puzzle.day,
+        part,
+        status,
+        answer,
+        duration,
+        samples: stats.map_or(1, |stats| stats.samples),
+        stats,
+        input: None,
+        error,
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if options.format == OutputFormat::Json && !options.quiet {
+        print_part_result(&part_result, options.format);
+    }
+
+    if let Some(answer) = &part_result.answer {
+        if options.record {
+            record_answer(puzzle, None, part, answer, options.format);
+        }
+
+        match submit_result(answer, puzzle, part, options.format) {
+            Some(Ok(outcome)) => {
+                print_message(options.format, format!("{ANSI_BOLD}{outcome}{ANSI_RESET}"));
+                if outcome == SubmissionOutcome::Correct {
+                    record_answer(puzzle, None, part, answer, options.format);
+                }
+            }
+            Some(Err(e)) => eprintln!("failed to submit result: {e}"),
+            None => {}
+        }
     }
+
+    part_result
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part on its own thread, so that a panicking part, or - if a timeout is set -
+/// a part that does not terminate, is reported instead of taking down the whole run.
+/// The timeout applies to the first run only, not to benching, which is why the commands do not
+/// accept a timeout when timing. A part that timed out keeps running in the background until the
+/// process exits, as threads cannot be killed.
+fn run_isolated<I: Clone + Send + 'static, T: Send + 'static>(
+    func: impl Fn(I) -> T + Send + 'static,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+    hook: impl Fn(&T) + Send + 'static,
+) -> Result<(T, Duration, Option<BenchStats>), PartStatus> {
+    let (started_sender, started_receiver) = mpsc::channel();
+    let (done_sender, done_receiver) = mpsc::channel();
+
+    let bench_config = options.is_timed.then_some(options.bench);
+    let is_verbose = options.format == OutputFormat::Text && !options.quiet;
+
+    let spawned = thread::Builder::new()
+        .name(format!("day {puzzle} part {part}"))
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                run_timed(func, input, bench_config.as_ref(), is_verbose, |result| {
+                    let _ = started_sender.send(());
+                    hook(result);
+                })
+            }));
+            let _ = done_sender.send(result);
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("Could not spawn thread for part {part}: {e}");
+        return Err(PartStatus::Panicked);
+    }
+
+    if let Some(timeout) = options.timeout {
+        if let Err(RecvTimeoutError::Timeout) = started_receiver.recv_timeout(timeout) {
+            return Err(PartStatus::TimedOut);
+        }
+    }
+
+    match done_receiver.recv() {
+        Ok(Ok(result)) => Ok(result),
+        _ => Err(PartStatus::Panicked),
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched according to the [`BenchConfig`].
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    bench_config: Option<&BenchConfig>,
+    is_verbose: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<BenchStats>) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -43,23 +515,42 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+    match bench_config {
+        Some(config) => {
+            let stats = bench(func, input, &base_time, config, is_verbose);
+            (result, stats.mean, Some(stats))
+        }
+        None => (result, base_time, None),
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    is_verbose: bool,
+) -> BenchStats {
+    if is_verbose {
+        let mut stdout = stdout();
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    // warm up caches and branch predictors before taking measurements.
+    let warmup_budget = config.budget / 10;
+    let warmup_timer = Instant::now();
+    for _ in 0..config.max_samples {
+        if warmup_timer.elapsed() >= warmup_budget {
+            break;
+        }
+        black_box(func(black_box(input.clone())));
+    }
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = config.samples.unwrap_or_else(|| {
+        (config.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+            .clamp(config.min_samples, config.max_samples)
+    });
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,26 +562,46 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&mut timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) => format!(
+            " ({duration:.1?} @ {} samples, median {:.1?}, min {:.1?}, p95 {:.1?}, σ {:.1?}, {} outliers)",
+            stats.samples, stats.median, stats.min, stats.p95, stats.std_dev, stats.outliers
+        ),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+/// Print the result of a part that was run without printing, e.g. on another thread.
+pub fn print_part_result(result: &PartResult, format: OutputFormat) {
+    match format {
+        OutputFormat::Text if result.status.is_failure() => {
+            print_failure(
+                &format!("Part {}", result.part),
+                result.status,
+                result.error.as_deref(),
+                &format_duration(&result.duration, None),
+            );
+        }
+        OutputFormat::Text => print_result(
+            &result.answer,
+            &format!("Part {}", result.part),
+            &format_duration(&result.duration, result.stats.as_ref()),
+        ),
+        OutputFormat::Json => println!("{}", JsonValue::from(result).stringify().unwrap()),
+    }
+}
+
+fn print_failure(part: &str, status: PartStatus, error: Option<&str>, duration_str: &str) {
+    print!("\r");
+    match error {
+        Some(error) => {
+            println!("{part}: ✖ {ANSI_ITALIC}{status}: {error}{ANSI_RESET}{duration_str}");
+        }
+        None => println!("{part}: ✖ {ANSI_ITALIC}{status}{ANSI_RESET}{duration_str}"),
     }
 }
 
@@ -129,14 +640,46 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Prints a status message, e.g. about a submission. Messages go to stderr in the JSON format, so
+/// that stdout only holds results.
+pub fn print_message(format: OutputFormat, message: impl Display) {
+    match format {
+        OutputFormat::Text => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
+    }
+}
+
+/// Records the accepted answer for a part and reports it if it is new.
+pub fn record_answer(
+    puzzle: Puzzle,
+    input: Option<&str>,
+    part: u8,
+    answer: &str,
+    format: OutputFormat,
+) {
+    match (Answers::record(puzzle, input, part, answer), input) {
+        (Ok(false), _) => {}
+        (Ok(true), Some(input)) => print_message(
+            format,
+            format!("Recorded answer for day {puzzle}, part {part}, input {input}."),
+        ),
+        (Ok(true), None) => print_message(
+            format,
+            format!("Recorded answer for day {puzzle}, part {part}."),
+        ),
+        (Err(e), _) => eprintln!("Failed to record answer: {e}"),
+    }
+}
+
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if the
+/// `--submit` flag is set for this part. Answers known to be wrong from the submission log
+/// are not submitted unless `--force` is set.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: Puzzle,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+    format: OutputFormat,
+) -> Option<Result<SubmissionOutcome, AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -159,11 +702,383 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let client = match AocClient::for_year(puzzle.year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            process::exit(1);
+        }
+    };
+
+    let answer = result.to_string();
+
+    if !args.contains(&"--force".into()) {
+        if let Err(reason) =
+            Submissions::read_from_file(puzzle.year).check(puzzle.day, part, &answer)
+        {
+            eprintln!("Not submitting: {reason} Pass --force to submit anyway.");
+            return None;
+        }
+    }
+
+    print_message(format, "Submitting result...");
+    let outcome = client.submit(puzzle.day, part, &answer);
+
+    if let Ok(outcome) = &outcome {
+        Submissions::log(puzzle, part, &answer, outcome);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(outcome)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "input".into(),
+            match &value.input {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "error".into(),
+            match &value.error {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected result.{key} to be a number."))
+        };
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected result.day to be a Day struct.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.status to be a string.")?
+            .parse()?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let input = match json.get("input") {
+            Some(JsonValue::String(x)) => Some(x.clone()),
+            Some(JsonValue::Null) | None => None,
+            _ => return Err("Expected result.input to be null or string.".into()),
+        };
+
+        let error = match json.get("error") {
+            Some(JsonValue::String(x)) => Some(x.clone()),
+            Some(JsonValue::Null) | None => None,
+            _ => return Err("Expected result.error to be null or string.".into()),
+        };
+
+        Ok(PartResult {
+            day,
+            part: number("part")? as u8,
+            answer: answer.cloned(),
+            duration: duration_from_nanos(number("duration_nanos")?),
+            samples: number("samples")? as u128,
+            stats,
+            status,
+            input,
+            error,
+        })
+    }
+}
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), nanos_to_json(value.mean));
+        map.insert("median_nanos".into(), nanos_to_json(value.median));
+        map.insert("min_nanos".into(), nanos_to_json(value.min));
+        map.insert("p95_nanos".into(), nanos_to_json(value.p95));
+        map.insert("std_dev_nanos".into(), nanos_to_json(value.std_dev));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        let nanos = |key: &str| number(key).map(duration_from_nanos);
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: nanos("mean_nanos")?,
+            median: nanos("median_nanos")?,
+            min: nanos("min_nanos")?,
+            p95: nanos("p95_nanos")?,
+            std_dev: nanos("std_dev_nanos")?,
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+fn nanos_to_json(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashMap, thread, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{
+        parse_samples, run_part_with, BenchStats, OutputFormat, PartResult, PartStatus, RunOptions,
+    };
+    use crate::day;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn computes_bench_stats() {
+        let stats = BenchStats::from_samples(&mut nanos(&[50, 10, 40, 20, 30]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.std_dev.as_nanos(), 14);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_zero_samples() {
+        assert_eq!(parse_samples("25").unwrap(), 25);
+        assert!(parse_samples("0").is_err());
+        assert!(parse_samples("many").is_err());
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let stats = BenchStats::from_samples(&mut nanos(&[10, 11, 10, 12, 11, 10, 500]));
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn roundtrips_bench_stats() {
+        let stats = BenchStats::from_samples(&mut nanos(&[10, 11, 10, 12, 11, 10, 500]));
+        let value = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&value).unwrap(), stats);
+    }
+
+    #[test]
+    fn parses_output_formats() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn serializes_part_results() {
+        let result = PartResult {
+            day: day!(7),
+            part: 2,
+            answer: Some("42".into()),
+            duration: Duration::from_nanos(1500),
+            samples: 10,
+            stats: None,
+            status: PartStatus::Solved,
+            input: None,
+            error: None,
+        };
+
+        let value = JsonValue::from(&result);
+        let map = value.get::<HashMap<String, JsonValue>>().unwrap();
+
+        assert_eq!(map["day"].get::<String>().unwrap(), "07");
+        assert_eq!(*map["part"].get::<f64>().unwrap(), 2_f64);
+        assert_eq!(map["answer"].get::<String>().unwrap(), "42");
+        assert_eq!(*map["duration_nanos"].get::<f64>().unwrap(), 1500_f64);
+        assert_eq!(*map["samples"].get::<f64>().unwrap(), 10_f64);
+        assert_eq!(map["status"].get::<String>().unwrap(), "solved");
+    }
+
+    #[test]
+    fn serializes_unsolved_part_results() {
+        let result = PartResult {
+            day: day!(7),
+            part: 1,
+            answer: None,
+            duration: Duration::from_nanos(10),
+            samples: 1,
+            stats: None,
+            status: PartStatus::Unsolved,
+            input: None,
+            error: None,
+        };
+
+        let value = JsonValue::from(&result);
+        let map = value.get::<HashMap<String, JsonValue>>().unwrap();
+
+        assert!(map["answer"].is_null());
+        assert_eq!(map["status"].get::<String>().unwrap(), "unsolved");
+    }
+
+    #[test]
+    fn parses_part_results() {
+        let json = r#"{"day":"07","part":2,"answer":"42","duration_nanos":1500,"samples":1,"status":"solved","stats":null}"#;
+        let result = PartResult::try_from(&json.parse::<JsonValue>().unwrap()).unwrap();
+
+        assert_eq!(result.day, day!(7));
+        assert_eq!(result.part, 2);
+        assert_eq!(result.answer.as_deref(), Some("42"));
+        assert_eq!(result.duration, Duration::from_nanos(1500));
+        assert_eq!(result.status, PartStatus::Solved);
+        assert!(result.stats.is_none());
+    }
+
+    #[test]
+    fn catches_panicking_parts() {
+        let options = RunOptions {
+            quiet: true,
+            ..RunOptions::default()
+        };
+
+        let result = run_part_with(
+            |_: ()| -> Option<u32> { panic!("Invalid input") },
+            (),
+            day!(1),
+            1,
+            &options,
+        );
+
+        assert_eq!(result.status, PartStatus::Panicked);
+        assert!(result.answer.is_none());
+    }
+
+    #[test]
+    fn reports_failing_parts() {
+        let options = RunOptions {
+            quiet: true,
+            ..RunOptions::default()
+        };
+
+        let result = run_part_with(
+            |input: &'static str| -> Result<Option<u32>, String> {
+                input
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| format!("`{input}` is no number"))
+            },
+            "x",
+            day!(1),
+            1,
+            &options,
+        );
+
+        assert_eq!(result.status, PartStatus::Failed);
+        assert_eq!(result.error.as_deref(), Some("`x` is no number"));
+        assert!(result.answer.is_none());
+
+        let result = run_part_with(
+            |_: ()| -> Result<Option<u32>, String> { Ok(Some(1)) },
+            (),
+            day!(1),
+            2,
+            &options,
+        );
+        assert_eq!(result.status, PartStatus::Solved);
+        assert_eq!(result.answer.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn times_out_parts() {
+        let options = RunOptions {
+            quiet: true,
+            timeout: Some(Duration::from_millis(20)),
+            ..RunOptions::default()
+        };
+
+        let result = run_part_with(
+            |_: ()| {
+                thread::sleep(Duration::from_secs(5));
+                Some(1)
+            },
+            (),
+            day!(1),
+            1,
+            &options,
+        );
+
+        assert_eq!(result.status, PartStatus::TimedOut);
+
+        let result = run_part_with(|_: ()| Some(1), (), day!(1), 2, &options);
+        assert_eq!(result.status, PartStatus::Solved);
+        assert_eq!(result.answer.as_deref(), Some("1"));
+    }
 }
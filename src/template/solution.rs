@@ -0,0 +1,29 @@
+use crate::template::runner::{PartResult, RunOptions};
+use crate::template::{Day, Puzzle, Year};
+
+/// A solution for a single day that can be invoked in-process.
+///
+/// Implementations are generated by the [`solution!`](crate::solution) macro. The main binary
+/// collects the implementations of every day in `src/bin` at build time, which allows the `all`
+/// and `time` commands to call each day's parts directly instead of spawning a child process.
+pub trait Solution: Sync {
+    /// The year this solution belongs to.
+    fn year(&self) -> Year;
+
+    /// The day this solution belongs to.
+    fn day(&self) -> Day;
+
+    /// Runs every part of the solution against `input`, printing results as it goes.
+    fn run(&self, input: &str, options: &RunOptions) -> Vec<PartResult>;
+}
+
+/// Looks up the solution registered for `puzzle`.
+pub fn find_solution<'a>(
+    solutions: &'a [&'a dyn Solution],
+    puzzle: Puzzle,
+) -> Option<&'a dyn Solution> {
+    solutions
+        .iter()
+        .copied()
+        .find(|solution| solution.year() == puzzle.year && solution.day() == puzzle.day)
+}
@@ -0,0 +1,301 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::SubmissionOutcome;
+use crate::template::{Day, Puzzle, Year};
+
+/// Path of the submissions file of a year, e.g. `data/submissions.json`.
+fn file_path(year: Year) -> PathBuf {
+    year.data_dir().join("submissions.json")
+}
+
+/// Represents a single submitted answer and the verdict it received.
+/// Only verdicts that judge the answer (correct, too high, too low, wrong) are logged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+}
+
+/// Represents the log of submitted answers.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let path = file_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file(year: Year) -> Self {
+        let Ok(s) = fs::read_to_string(file_path(year)) else {
+            return Submissions::default();
+        };
+
+        match Submissions::try_from(s) {
+            Ok(submissions) => submissions,
+            Err(e) => {
+                eprintln!("{e}");
+                Submissions::default()
+            }
+        }
+    }
+
+    /// Checks an answer against previous submissions of a part.
+    /// Returns the reason if the answer is known to be wrong.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), String> {
+        let previous = self.data.iter().filter(|s| s.day == day && s.part == part);
+        let value = answer.trim().parse::<i128>().ok();
+
+        for submission in previous {
+            if submission.answer == answer {
+                return match submission.outcome {
+                    SubmissionOutcome::Correct => Ok(()),
+                    _ => Err(format!(
+                        "`{answer}` was already submitted and rejected ({}).",
+                        outcome_to_str(&submission.outcome)
+                    )),
+                };
+            }
+
+            let (Some(value), Ok(bound)) = (value, submission.answer.trim().parse::<i128>()) else {
+                continue;
+            };
+
+            match submission.outcome {
+                SubmissionOutcome::TooHigh if value >= bound => {
+                    return Err(format!(
+                        "`{answer}` is not below `{bound}`, which was too high."
+                    ));
+                }
+                SubmissionOutcome::TooLow if value <= bound => {
+                    return Err(format!(
+                        "`{answer}` is not above `{bound}`, which was too low."
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends a submission to the log. Outcomes that do not judge the answer are ignored.
+    pub fn push(&mut self, day: Day, part: u8, answer: &str, outcome: &SubmissionOutcome) {
+        if outcome_to_str(outcome).is_empty() {
+            return;
+        }
+
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.into(),
+            outcome: outcome.clone(),
+        });
+    }
+
+    /// Logs a submission in the submissions file of the puzzle's year.
+    pub fn log(puzzle: Puzzle, part: u8, answer: &str, outcome: &SubmissionOutcome) {
+        let mut submissions = Submissions::read_from_file(puzzle.year);
+        let len = submissions.data.len();
+        submissions.push(puzzle.day, part, answer, outcome);
+
+        if submissions.data.len() == len {
+            return;
+        }
+
+        if let Err(e) = submissions.store_file(puzzle.year) {
+            eprintln!("Failed to log submission: {e}");
+        }
+    }
+}
+
+fn outcome_to_str(outcome: &SubmissionOutcome) -> &'static str {
+    match outcome {
+        SubmissionOutcome::Correct => "correct",
+        SubmissionOutcome::TooHigh => "too high",
+        SubmissionOutcome::TooLow => "too low",
+        SubmissionOutcome::Wrong => "wrong",
+        SubmissionOutcome::RateLimited { .. } | SubmissionOutcome::AlreadySolved => "",
+    }
+}
+
+fn outcome_from_str(s: &str) -> Option<SubmissionOutcome> {
+    match s {
+        "correct" => Some(SubmissionOutcome::Correct),
+        "too high" => Some(SubmissionOutcome::TooHigh),
+        "too low" => Some(SubmissionOutcome::TooLow),
+        "wrong" => Some(SubmissionOutcome::Wrong),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(outcome_to_str(&value.outcome).into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .and_then(|outcome| outcome_from_str(outcome))
+            .ok_or("Expected submission.outcome to be a submission outcome.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+    use crate::template::aoc_client::SubmissionOutcome;
+
+    use super::Submissions;
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(1), 1, "42", &SubmissionOutcome::Wrong);
+
+        assert!(submissions.check(day!(1), 1, "42").is_err());
+        assert!(submissions.check(day!(1), 1, "43").is_ok());
+        assert!(submissions.check(day!(1), 2, "42").is_ok());
+        assert!(submissions.check(day!(2), 1, "42").is_ok());
+    }
+
+    #[test]
+    fn rejects_answers_outside_bounds() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(1), 1, "100", &SubmissionOutcome::TooHigh);
+        submissions.push(day!(1), 1, "10", &SubmissionOutcome::TooLow);
+
+        assert!(submissions.check(day!(1), 1, "100").is_err());
+        assert!(submissions.check(day!(1), 1, "150").is_err());
+        assert!(submissions.check(day!(1), 1, "5").is_err());
+        assert!(submissions.check(day!(1), 1, "50").is_ok());
+        assert!(submissions.check(day!(1), 1, "abc").is_ok());
+    }
+
+    #[test]
+    fn ignores_unjudged_outcomes() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(1), 1, "42", &SubmissionOutcome::AlreadySolved);
+        submissions.push(
+            day!(1),
+            1,
+            "42",
+            &SubmissionOutcome::RateLimited { wait: None },
+        );
+
+        assert!(submissions.data.is_empty());
+        assert!(submissions.check(day!(1), 1, "42").is_ok());
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(3), 2, "7", &SubmissionOutcome::TooLow);
+        submissions.push(day!(3), 2, "9", &SubmissionOutcome::Correct);
+
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+}
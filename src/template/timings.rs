@@ -1,37 +1,114 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    runner::{BenchStats, PartStatus},
+    Day, Year,
+};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Path of the timings file of a year, e.g. `data/timings.json`.
+fn file_path(year: Year) -> PathBuf {
+    year.data_dir().join("timings.json")
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Status of each part when it was run, e.g. whether it panicked or timed out.
+    pub part_1_status: Option<PartStatus>,
+    pub part_2_status: Option<PartStatus>,
     pub total_nanos: f64,
 }
 
+/// A timing that was stored at some point, used to track how benchmarks evolve.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the git commit the timing was taken at, if available.
+    pub commit: Option<String>,
+    pub timing: Timing,
+}
+
+impl HistoryEntry {
+    /// Stamps a timing with the current time and git commit.
+    pub fn now(timing: Timing) -> Self {
+        HistoryEntry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+            commit: current_commit(),
+            timing,
+        }
+    }
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/// A day whose timing got slower than allowed when compared to the stored timing.
+#[derive(Clone, Debug)]
+pub struct Regression {
+    pub day: Day,
+    pub previous_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl Regression {
+    /// Relative change of the timing in percent.
+    pub fn change_percent(&self) -> f64 {
+        (self.current_nanos / self.previous_nanos - 1.0) * 100.0
+    }
+}
+
 /// Represents benchmark times for a set of days.
+/// `data` holds the latest timing per day, `history` every timing that was ever stored.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
     pub data: Vec<Timing>,
+    pub history: Vec<HistoryEntry>,
 }
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let path = file_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        let s = fs::read_to_string(file_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -45,6 +122,7 @@ impl Timings {
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of both is kept, with the latest timings of `other` appended to it.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
@@ -58,8 +136,35 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+
+        let mut history = self.history.clone();
+        history.extend(new.history.iter().cloned());
+        history.extend(new.data.iter().cloned().map(HistoryEntry::now));
+
+        Timings { data, history }
+    }
+
+    /// Find days in `new` that are slower than in `self` by more than `threshold_percent`.
+    pub fn compare(&self, new: &Self, threshold_percent: f64) -> Vec<Regression> {
+        new.data
+            .iter()
+            .filter_map(|timing| {
+                let previous = self.data.iter().find(|t| t.day == timing.day)?;
+
+                if previous.total_nanos <= 0.0 {
+                    return None;
+                }
+
+                let regression = Regression {
+                    day: timing.day,
+                    previous_nanos: previous.total_nanos,
+                    current_nanos: timing.total_nanos,
+                };
+
+                (regression.change_percent() > threshold_percent).then_some(regression)
+            })
+            .collect()
     }
 
     /// Sum up total duration of timings as millis.
@@ -70,7 +175,7 @@ impl Timings {
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.part_1_nanos.is_some() && t.part_2_nanos.is_some())
     }
 }
 
@@ -85,6 +190,11 @@ impl From<Timings> for JsonValue {
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -95,19 +205,34 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // history is optional, files written before it was tracked lack it.
+        let json_history = match json.get("history") {
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -121,25 +246,48 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
+            "part_1_nanos".into(),
+            match value.part_1_nanos {
+                Some(x) => JsonValue::Number(x),
                 None => JsonValue::Null,
             },
         );
 
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
+            "part_2_nanos".into(),
+            match value.part_2_nanos {
+                Some(x) => JsonValue::Number(x),
                 None => JsonValue::Null,
             },
         );
 
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            map.insert(
+                key.into(),
+                match stats {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        for (key, status) in [
+            ("part_1_status", value.part_1_status),
+            ("part_2_status", value.part_2_status),
+        ] {
+            map.insert(
+                key.into(),
+                match status {
+                    Some(x) => JsonValue::String(x.to_string()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -158,30 +306,130 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let part_nanos = |part: u8| -> Result<Option<f64>, String> {
+            if let Some(v) = json.get(&format!("part_{part}_nanos")) {
+                return match v {
+                    JsonValue::Null => Ok(None),
+                    JsonValue::Number(x) => Ok(Some(*x)),
+                    _ => Err(format!(
+                        "Expected timing.part_{part}_nanos to be null or number."
+                    )),
+                };
+            }
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            // files written before durations were stored as numbers hold formatted strings.
+            match json.get(&format!("part_{part}")) {
+                Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::String(x)) => parse_duration_str(x).map(Some).ok_or(format!(
+                    "Could not parse timing.part_{part} duration `{x}`."
+                )),
+                _ => Err(format!(
+                    "Expected timing.part_{part}_nanos to be null or number."
+                )),
+            }
+        };
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // stats are optional, timings stored before they were collected lack them.
+        let stats = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => BenchStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        // statuses are optional, timings stored before they were collected lack them.
+        let status = |key: &str| match json.get(key) {
+            Some(JsonValue::String(x)) => x.parse::<PartStatus>().map(Some),
+            Some(JsonValue::Null) | None => Ok(None),
+            _ => Err(format!("Expected timing.{key} to be null or string.")),
+        };
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            part_1_nanos: part_nanos(1)?,
+            part_2_nanos: part_nanos(2)?,
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            part_1_status: status("part_1_status")?,
+            part_2_status: status("part_2_status")?,
             total_nanos,
         })
     }
 }
 
+/// Parse a duration formatted with `{:?}`, e.g. `74.13ms`, into nanoseconds.
+fn parse_duration_str(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let (value, factor) = if let Some(x) = s.strip_suffix("ns") {
+        (x, 1_f64)
+    } else if let Some(x) = s.strip_suffix("µs") {
+        (x, 1000_f64)
+    } else if let Some(x) = s.strip_suffix("ms") {
+        (x, 1_000_000_f64)
+    } else {
+        (s.strip_suffix('s')?, 1_000_000_000_f64)
+    };
+
+    value.trim().parse::<f64>().ok().map(|x| x * factor)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected history.commit to be null or string.")?;
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected history.timing to be present.")
+            .map(Timing::try_from)??;
+
+        Ok(HistoryEntry {
+            timestamp: timestamp as u64,
+            commit: commit.cloned(),
+            timing,
+        })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
@@ -195,28 +443,44 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1_nanos: Some(10_000_000_f64),
+                    part_2_nanos: Some(20_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1_nanos: Some(30_000_000_f64),
+                    part_2_nanos: Some(40_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
+                    part_1_nanos: Some(40_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 4e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{runner::PartStatus, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -225,16 +489,76 @@ mod tests {
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
-            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.part_1_nanos, Some(1_000_000_f64));
+            assert_eq!(timing.part_2_nanos, None);
+            assert_eq!(timing.part_1_stats, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_numeric_json_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1_nanos": 1500, "part_2_nanos": null, "total_nanos": 1500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_nanos, Some(1500_f64));
+            assert_eq!(timing.part_2_nanos, None);
+        }
+
+        #[test]
+        fn migrates_formatted_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ns", "part_2": "1.5µs", "total_nanos": 0 }, { "day": "02", "part_1": "2.5s", "part_2": "10ms", "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_1_nanos, Some(74.13_f64));
+            assert_eq!(timings.data[0].part_2_nanos, Some(1500_f64));
+            assert_eq!(timings.data[1].part_1_nanos, Some(2_500_000_000_f64));
+            assert_eq!(timings.data[1].part_2_nanos, Some(10_000_000_f64));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unparsable_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "mean_nanos": 1000000, "median_nanos": 900000, "min_nanos": 800000, "p95_nanos": 1500000, "std_dev_nanos": 100000, "outliers": 1 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.median.as_nanos(), 900_000);
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_statuses() {
+            let json = r#"{ "data": [{ "day": "01", "part_1_nanos": 1500, "part_2_nanos": null, "total_nanos": 1500, "part_1_status": "solved", "part_2_status": "timed out" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_status, Some(PartStatus::Solved));
+            assert_eq!(timing.part_2_status, Some(PartStatus::TimedOut));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 0);
+            assert_eq!(timings.history.len(), 0);
+        }
+
+        #[test]
+        fn handles_history() {
+            let json = r#"{ "data": [], "history": [{ "timestamp": 1700000000, "commit": "abc1234", "timing": { "day": "03", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 } }, { "timestamp": 1700000100, "commit": null, "timing": { "day": "03", "part_1": "2ms", "part_2": null, "total_nanos": 2000000 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 2);
+            assert_eq!(timings.history[0].timestamp, 1_700_000_000);
+            assert_eq!(timings.history[0].commit, Some("abc1234".into()));
+            assert_eq!(timings.history[0].timing.day, day!(3));
+            assert_eq!(timings.history[1].commit, None);
         }
 
         #[test]
@@ -286,13 +610,18 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: Some(2_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -300,13 +629,18 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -314,13 +648,18 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: None,
-                    part_2: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 0.0,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -338,10 +677,15 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
-                    part_1: None,
-                    part_2: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -358,10 +702,15 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
-                    part_1: None,
-                    part_2: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
@@ -387,5 +736,62 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn appends_history() {
+            let timings = Timings::default().merge(&get_mock_timings());
+            assert_eq!(timings.history.len(), 3);
+
+            let merged = timings.merge(&get_mock_timings());
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.history.len(), 6);
+            assert_eq!(merged.history[3].timing.day, day!(1));
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use super::get_mock_timings;
+
+        fn timings_with(day: u8, total_nanos: f64) -> Timings {
+            Timings {
+                data: vec![Timing {
+                    day: crate::template::Day::new(day).unwrap(),
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    total_nanos,
+                }],
+                history: vec![],
+            }
+        }
+
+        #[test]
+        fn detects_regressions() {
+            let regressions = get_mock_timings().compare(&timings_with(2, 8e+10), 10.0);
+            assert_eq!(regressions.len(), 1);
+            assert_eq!(regressions[0].day, day!(2));
+            assert!((regressions[0].change_percent() - 14.285_714).abs() < 1e-3);
+        }
+
+        #[test]
+        fn ignores_changes_within_threshold() {
+            let regressions = get_mock_timings().compare(&timings_with(2, 7.5e+10), 10.0);
+            assert_eq!(regressions.len(), 0);
+        }
+
+        #[test]
+        fn ignores_improvements_and_new_days() {
+            let timings = get_mock_timings();
+            assert_eq!(timings.compare(&timings_with(1, 1e+10), 10.0).len(), 0);
+            assert_eq!(timings.compare(&timings_with(3, 1e+10), 10.0).len(), 0);
+        }
     }
 }
@@ -0,0 +1,220 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::template::{layout::FLAT_YEAR, Day};
+
+/// The first year of advent.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. an integer from 2015 onwards).
+///
+/// The files of [`Year::FLAT`] keep the flat layout (`src/bin/NN.rs`, `data/inputs/NN.txt`), while
+/// the files of any other year are namespaced (`src/bin/YYYY-NN.rs`, `data/YYYY/inputs/NN.txt`).
+/// The layout does not depend on `AOC_YEAR`, which only picks the year of the commands.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2020).unwrap();
+/// assert_eq!(year.to_string(), "2020")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year of the solutions that predate multi-year support, whose files keep the flat layout.
+    pub const FLAT: Year = Year(FLAT_YEAR);
+
+    /// Creates a [`Year`] from the provided value if it's a year of advent,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the default year set in the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Whether the files of this year keep the flat layout, see [`Year::FLAT`].
+    pub fn is_flat(self) -> bool {
+        self == Year::FLAT
+    }
+
+    /// Directory holding the data of this year: `data` for [`Year::FLAT`], `data/YYYY` otherwise.
+    pub fn data_dir(self) -> PathBuf {
+        if self.is_flat() {
+            PathBuf::from("data")
+        } else {
+            PathBuf::from("data").join(self.to_string())
+        }
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of advent, i.e. {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle, identified by its year and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub fn new(year: Year, day: Day) -> Self {
+        Puzzle { year, day }
+    }
+
+    /// Creates a puzzle of `year`, or of [`Year::FLAT`] if [`None`].
+    pub fn resolve(year: Option<Year>, day: Day) -> Self {
+        Puzzle {
+            year: year.unwrap_or(Year::FLAT),
+            day,
+        }
+    }
+
+    /// Name of the binary that solves this puzzle, e.g. `07` or `2021-07`.
+    pub fn bin_name(self) -> String {
+        if self.year.is_flat() {
+            self.day.to_string()
+        } else {
+            format!("{}-{}", self.year, self.day)
+        }
+    }
+
+    /// Path of the module that solves this puzzle.
+    pub fn bin_path(self) -> PathBuf {
+        PathBuf::from("src")
+            .join("bin")
+            .join(format!("{}.rs", self.bin_name()))
+    }
+
+    /// Path of a data file of this puzzle, e.g. `data/inputs/07.txt` for `("inputs", ".txt")`.
+    pub fn data_file(self, folder: &str, suffix: &str) -> PathBuf {
+        self.year
+            .data_dir()
+            .join(folder)
+            .join(format!("{}{suffix}", self.day))
+    }
+}
+
+impl From<Day> for Puzzle {
+    /// Creates a puzzle of [`Year::FLAT`].
+    fn from(day: Day) -> Self {
+        Puzzle::resolve(None, day)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.year.is_flat() {
+            write!(f, "{}", self.day)
+        } else {
+            write!(f, "{} {}", self.year, self.day)
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!("invalid year `", $year, "`, expecting 2015 or later"),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Puzzle, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2020".parse::<Year>().unwrap(), Year(2020));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn namespaces_other_years() {
+        let flat = Puzzle::from(day!(7));
+        assert_eq!(flat.year, Year(2020));
+        assert_eq!(flat.bin_name(), "07");
+        assert_eq!(flat.bin_path(), PathBuf::from("src/bin/07.rs"));
+        assert_eq!(
+            flat.data_file("inputs", ".txt"),
+            PathBuf::from("data/inputs/07.txt")
+        );
+
+        let other = Puzzle::new(Year(2021), day!(7));
+        assert_eq!(other.bin_name(), "2021-07");
+        assert_eq!(
+            other.data_file("puzzles", ".md"),
+            PathBuf::from("data/2021/puzzles/07.md")
+        );
+    }
+
+    #[test]
+    fn resolves_solution_years() {
+        assert_eq!(Puzzle::resolve(None, day!(7)).year, Year::FLAT);
+        assert_eq!(
+            Puzzle::resolve(Some(Year(2021)), day!(7)),
+            Puzzle::new(Year(2021), day!(7))
+        );
+    }
+}